@@ -1,8 +1,103 @@
-use anyhow::Result;
+use std::{env, fs, path::Path};
+
+use anyhow::{bail, Result};
 use vergen::{vergen, Config, TimestampKind};
 
+/// Parser shared with application.
+#[allow(dead_code)]
+#[path = "src/text.rs"]
+mod text;
+
+#[allow(dead_code)]
+#[path = "src/model"]
+mod model {
+    mod import;
+    mod notation;
+    mod parse;
+    mod stress;
+    mod syllable;
+    mod syntax;
+    mod variant;
+    mod word;
+
+    pub use self::{
+        import::{import, Format},
+        notation::Notation,
+        word::Word,
+    };
+}
+
+/// Get ids of decks compiled into application with paths of their word lists.
+///
+/// Decks are listed in `builtin_decks.rs` shared with application.
+macro_rules! builtin_decks {
+    ($($id:literal => {
+        path: $path:literal,
+        $($field:ident: $value:literal),* $(,)?
+    }),* $(,)?) => {
+        [$(($id, concat!("src/model/", $path))),*]
+    };
+}
+
+/// Sources shared with application that build script is compiled from.
+const SHARED_SOURCES: [&str; 10] = [
+    "src/text.rs",
+    "src/model/builtin_decks.rs",
+    "src/model/import.rs",
+    "src/model/notation.rs",
+    "src/model/parse.rs",
+    "src/model/stress.rs",
+    "src/model/syllable.rs",
+    "src/model/syntax.rs",
+    "src/model/variant.rs",
+    "src/model/word.rs",
+];
+
 fn main() -> Result<()> {
+    // Once any path is printed, Cargo reruns build script only when printed paths change.
+    for path in SHARED_SOURCES {
+        println!("cargo:rerun-if-changed={path}");
+    }
+    // Build time shown in footer is updated on any change of application.
+    println!("cargo:rerun-if-changed=src");
     let mut config = Config::default();
     *config.build_mut().kind_mut() = TimestampKind::DateAndTime;
-    vergen(config)
+    vergen(config)?;
+    check_decks()
+}
+
+/// Parse built-in word lists, failing the build if any of them has errors.
+///
+/// Lists may be written in `data.txt` syntax or imported from table or JSON, by extension.
+///
+/// With `preparsed` feature enabled parsed words are written to `OUT_DIR`.
+fn check_decks() -> Result<()> {
+    let mut failed = 0;
+    for (id, path) in include!("src/model/builtin_decks.rs") {
+        println!("cargo:rerun-if-changed={path}");
+        let source = fs::read_to_string(path)?;
+        let (words, errors, warnings) = model::import(
+            &source,
+            model::Format::from_name(path),
+            model::Notation::Uppercase,
+        );
+        for warning in warnings {
+            for line in warning.render(path).lines() {
+                println!("cargo:warning={line}");
+            }
+        }
+        for error in &errors {
+            eprintln!("{}\n", error.render(path));
+        }
+        failed += errors.len();
+
+        if env::var_os("CARGO_FEATURE_PREPARSED").is_some() {
+            let out = Path::new(&env::var("OUT_DIR")?).join(format!("{id}.json"));
+            fs::write(out, serde_json::to_string(&words)?)?;
+        }
+    }
+    if failed > 0 {
+        bail!("built-in word lists have {failed} errors");
+    }
+    Ok(())
 }
@@ -0,0 +1,16 @@
+#!/bin/sh
+# Generate service worker that caches every file of the build.
+# Run by Trunk after build, see `Trunk.toml`.
+set -eu
+
+cd "$TRUNK_STAGING_DIR"
+assets=$(find . -type f ! -name sw.js | sed 's|^\./||' | sort)
+# Version changes with contents of any file, so clients drop outdated cache.
+version=$(cat $assets | cksum | cut -d ' ' -f 1)
+list=$(printf '"%s", ' $assets | sed 's/, $//')
+
+awk -v version="$version" -v assets="$list" '{
+    gsub(/__VERSION__/, version)
+    gsub(/__ASSETS__/, assets)
+    print
+}' "$TRUNK_SOURCE_DIR/pwa/sw.js" > sw.js
@@ -0,0 +1,46 @@
+// Service worker that keeps the trainer available offline.
+// `__VERSION__` and `__ASSETS__` are filled in by `service-worker.sh` on each Trunk build.
+
+const PREFIX = "emphasis-trainer-";
+const CACHE = PREFIX + "__VERSION__";
+const ASSETS = ["./", __ASSETS__];
+
+// Files of the build are cached as a whole, so the app never mixes files of different builds.
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(CACHE)
+      .then((cache) => cache.addAll(ASSETS))
+      .then(() => self.skipWaiting())
+  );
+});
+
+// Caches of previous builds are removed once the new one is ready.
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys
+            .filter((key) => key.startsWith(PREFIX) && key !== CACHE)
+            .map((key) => caches.delete(key))
+        )
+      )
+      .then(() => self.clients.claim())
+  );
+});
+
+// Names of built files contain their hash, so cached ones are always up to date.
+// Routes and shared decks are kept in URL fragment, which never reaches service worker,
+// but query added to page link by other sites is ignored as well.
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  if (request.method !== "GET") {
+    return;
+  }
+  const options = { ignoreSearch: request.mode === "navigate" };
+  event.respondWith(
+    caches.match(request, options).then((cached) => cached || fetch(request))
+  );
+});
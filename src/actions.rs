@@ -0,0 +1,26 @@
+//! Actions that change state of word: suspend, bury and flag it.
+
+use yew::{html, Callback, Html};
+
+use crate::model::{WordHash, WordState};
+
+/// Render buttons that change state of word.
+pub fn word_actions(callback: &Callback<crate::Msg>, word: WordHash, state: WordState) -> Html {
+    let suspend = callback.reform(move |_| crate::Msg::Suspend(word, !state.suspended));
+    let bury = callback.reform(move |_| crate::Msg::Bury(word));
+    let flag = callback.reform(move |_| crate::Msg::Flag(word, !state.flagged));
+
+    html! {
+        <div class="panel-block word-actions">
+            <button class="button is-small is-text" onclick={suspend}>
+            {if state.suspended { "Вернуть слово" } else { "Больше не показывать" }}
+            </button>
+            <button class="button is-small is-text" onclick={bury} disabled={state.buried}>
+            {"Отложить до завтра"}
+            </button>
+            <button class="button is-small is-text" onclick={flag}>
+            {if state.flagged { "Снять отметку об ошибке" } else { "Сообщить об ошибке" }}
+            </button>
+        </div>
+    }
+}
@@ -0,0 +1,269 @@
+//! Custom deck card allows user to load their own word list.
+
+use gloo::file::{callbacks::FileReader, File};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::{html, Callback, Component, Context, Event, Html, Properties, TargetCast};
+
+use crate::errors::{ParseErrors, ParseErrorsProperties};
+use crate::model::{
+    import, CustomDeck, Document, Format, Notation, ParseError, ParseWarning, StressStyle,
+};
+
+#[derive(PartialEq, Properties)]
+pub struct CustomDeckProperties {
+    pub callback: Callback<crate::Msg>,
+    pub deck: Option<CustomDeck>,
+}
+
+pub enum CustomDeckMsg {
+    Select(File),
+    Loaded(String, String),
+    Failed(String),
+    Notation(Notation),
+    Delete,
+}
+
+pub struct CustomDeckCard {
+    reader: Option<FileReader>,
+    /// Errors of the latest rejected file.
+    rejected: Option<(String, Vec<ParseError>)>,
+    /// Result of import of the current deck, so it isn't parsed on each render.
+    imported: Option<Imported>,
+}
+
+/// Custom deck along with what its import found.
+struct Imported {
+    deck: CustomDeck,
+    words: usize,
+    errors: Vec<ParseError>,
+    warnings: Vec<ParseWarning>,
+    /// Link to download deck in `data.txt` syntax.
+    formatted: Option<String>,
+}
+
+impl Imported {
+    fn new(deck: &CustomDeck) -> Self {
+        let (words, errors, warnings) = import(&deck.source, deck.format, deck.notation);
+        // Imported lists are converted to `data.txt` syntax.
+        let document = match deck.format {
+            Format::Text => Document::parse(&deck.source, deck.notation).ok(),
+            _ => Some(Document::from_words(&words, StressStyle::Uppercase)),
+        };
+        let formatted = document.map(|document| {
+            format!(
+                "data:text/plain;charset=utf-8,{}",
+                js_sys::encode_uri_component(&document.format())
+            )
+        });
+        Imported {
+            deck: deck.clone(),
+            words: words.len(),
+            errors,
+            warnings,
+            formatted,
+        }
+    }
+}
+
+impl Component for CustomDeckCard {
+    type Message = CustomDeckMsg;
+    type Properties = CustomDeckProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        CustomDeckCard {
+            reader: None,
+            rejected: None,
+            imported: ctx.props().deck.as_ref().map(Imported::new),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        let deck = ctx.props().deck.as_ref();
+        if self.imported.as_ref().map(|imported| &imported.deck) != deck {
+            self.imported = deck.map(Imported::new);
+        }
+        true
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            CustomDeckMsg::Select(file) => {
+                let name = file.name();
+                let link = ctx.link().clone();
+                self.reader = Some(gloo::file::callbacks::read_as_text(
+                    &file,
+                    move |res| match res {
+                        Ok(source) => link.send_message(CustomDeckMsg::Loaded(name, source)),
+                        Err(e) => link.send_message(CustomDeckMsg::Failed(e.to_string())),
+                    },
+                ));
+            }
+            CustomDeckMsg::Loaded(name, source) => {
+                self.reader = None;
+                let format = Format::from_name(&name);
+                let notation = Notation::default();
+                let (words, errors, _) = import(&source, format, notation);
+                if words.is_empty() {
+                    self.rejected = Some((name, errors));
+                } else {
+                    self.rejected = None;
+                    let deck = CustomDeck {
+                        name,
+                        source,
+                        format,
+                        notation,
+                    };
+                    ctx.props()
+                        .callback
+                        .emit(crate::Msg::CustomDeck(Some(deck)));
+                }
+            }
+            CustomDeckMsg::Failed(error) => {
+                self.reader = None;
+                gloo::console::error!(format!("Failed to read file: {error}"));
+            }
+            CustomDeckMsg::Notation(notation) => {
+                if let Some(deck) = &ctx.props().deck {
+                    let deck = CustomDeck {
+                        notation,
+                        ..deck.clone()
+                    };
+                    ctx.props()
+                        .callback
+                        .emit(crate::Msg::CustomDeck(Some(deck)));
+                }
+            }
+            CustomDeckMsg::Delete => {
+                self.rejected = None;
+                ctx.props().callback.emit(crate::Msg::CustomDeck(None));
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let onchange = ctx.link().batch_callback(|e: Event| {
+            e.target_unchecked_into::<HtmlInputElement>()
+                .files()
+                .and_then(|files| files.get(0))
+                .map(|file| CustomDeckMsg::Select(File::from(file)))
+        });
+
+        html! {
+            <>
+                <div class="panel-block custom-deck">
+                    <p>
+                        {"Список загружается из текстового файла в том же формате, что и "}
+                        <a href="https://github.com/ClayenKitten/emphasis-trainer/blob/master/src/model/data.txt">{"встроенный"}</a>
+                        {": по одному слову на строке, ударная гласная — заглавная. "}
+                        {"Ударение можно отметить и знаком ударения, апострофом после гласной или плюсом перед ней."}
+                    </p>
+                    <p>
+                        {"Также подойдёт таблица CSV или TSV со столбцами «слово», «уточнение», «лемма», «форма», «группа», «пояснение» и «раздел» "}
+                        {"или JSON-массив объектов с полями word, detail, lemma, form, group, explanation и category. "}
+                        {"Обязателен только столбец «слово»."}
+                    </p>
+                    <div class="file">
+                        <label class="file-label">
+                            <input class="file-input" type="file" accept={Format::ACCEPT} {onchange}/>
+                            <span class="file-cta">
+                                <span class="file-label">{"Выбрать файл"}</span>
+                            </span>
+                        </label>
+                    </div>
+                </div>
+                {self.view_rejected()}
+                {self.view_deck(ctx)}
+            </>
+        }
+    }
+}
+
+impl CustomDeckCard {
+    fn view_rejected(&self) -> Html {
+        match &self.rejected {
+            Some((name, errors)) => {
+                let props = ParseErrorsProperties {
+                    file: name.clone(),
+                    errors: errors.clone(),
+                    warnings: Vec::new(),
+                };
+                html! {
+                    <div class="panel-block custom-deck">
+                        <p class="has-text-danger">{format!("В файле «{name}» не найдено ни одного слова.")}</p>
+                        <ParseErrors ..props/>
+                    </div>
+                }
+            }
+            None => html!(),
+        }
+    }
+
+    fn view_deck(&self, ctx: &Context<Self>) -> Html {
+        let imported = match &self.imported {
+            Some(imported) => imported,
+            None => return html!(),
+        };
+        let deck = &imported.deck;
+        let summary = format!(
+            "Загружен список «{}»: слов — {}, ошибок — {}, предупреждений — {}.",
+            deck.name,
+            imported.words,
+            imported.errors.len(),
+            imported.warnings.len()
+        );
+        let on_delete = ctx.link().callback(|_| CustomDeckMsg::Delete);
+        let on_notation = ctx.link().batch_callback(|e: Event| {
+            let index = e
+                .target_unchecked_into::<HtmlSelectElement>()
+                .selected_index();
+            usize::try_from(index)
+                .ok()
+                .and_then(|i| Notation::ALL.get(i).copied())
+                .map(CustomDeckMsg::Notation)
+        });
+        let formatted = imported.formatted.clone();
+        let download = match deck.name.rsplit_once('.') {
+            Some((stem, _)) if deck.format != Format::Text => format!("{stem}.txt"),
+            _ => deck.name.clone(),
+        };
+        let props = ParseErrorsProperties {
+            file: deck.name.clone(),
+            errors: imported.errors.clone(),
+            warnings: imported.warnings.clone(),
+        };
+
+        html! {
+            <>
+                <div class="panel-block custom-deck">
+                    <p>{summary}</p>
+                    <ParseErrors ..props/>
+                </div>
+                <div class="panel-block custom-deck">
+                    <label class="label">{"Обозначение ударения"}</label>
+                    <div class="select">
+                        <select onchange={on_notation}>
+                            {
+                                Notation::ALL.iter().map(|notation| html! {
+                                    <option selected={*notation == deck.notation}>{notation.name()}</option>
+                                }).collect::<Html>()
+                            }
+                        </select>
+                    </div>
+                </div>
+                if let Some(href) = formatted {
+                    <div class="panel-block">
+                        <a class="button is-fullwidth" {href} {download}>
+                        {"Скачать, упорядочив слова"}
+                        </a>
+                    </div>
+                }
+                <div class="panel-block">
+                    <button class="button is-danger is-outlined is-fullwidth" onclick={on_delete}>
+                    {"Удалить список"}
+                    </button>
+                </div>
+            </>
+        }
+    }
+}
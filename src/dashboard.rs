@@ -0,0 +1,75 @@
+//! Dashboard shows learning progress over all words.
+
+use yew::{function_component, html, Html, Properties};
+
+use crate::model::{Group, GroupProgress, Progress};
+
+#[derive(PartialEq, Properties)]
+pub struct DashboardProperties {
+    pub progress: Progress,
+    pub groups: Vec<GroupProgress>,
+}
+
+#[function_component(Dashboard)]
+pub fn dashboard(props: &DashboardProperties) -> Html {
+    let progress = &props.progress;
+    let total = progress.total().max(1);
+
+    html! {
+        <>
+            <div class="panel-block dashboard">
+                <p>{format!("Всего слов: {}. Ждут повторения: {}.", progress.total(), progress.due)}</p>
+            </div>
+            <div class="panel-block dashboard">
+                <table class="table is-fullwidth">
+                    <thead>
+                        <tr>
+                            <th>{"Уровень"}</th>
+                            <th>{"Слов"}</th>
+                            <th class="is-fullwidth"></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                    {
+                        (0..Group::COUNT).map(|level| html! {
+                            <tr>
+                                <td>{level}</td>
+                                <td>{progress.levels[level]}</td>
+                                <td>
+                                    <progress class="progress is-link" value={progress.levels[level].to_string()} max={total.to_string()}/>
+                                </td>
+                            </tr>
+                        }).collect::<Html>()
+                    }
+                    </tbody>
+                </table>
+            </div>
+            if !props.groups.is_empty() {
+                <div class="panel-block dashboard">
+                    <table class="table is-fullwidth">
+                        <thead>
+                            <tr>
+                                <th>{"Группа"}</th>
+                                <th>{"Слов"}</th>
+                                <th class="is-fullwidth">{"Освоено"}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                        {
+                            props.groups.iter().map(|group| html! {
+                                <tr>
+                                    <td>{&group.name}</td>
+                                    <td>{group.words}</td>
+                                    <td>
+                                        <progress class="progress is-success" value={group.levels.to_string()} max={group.max_levels().to_string()}/>
+                                    </td>
+                                </tr>
+                            }).collect::<Html>()
+                        }
+                        </tbody>
+                    </table>
+                </div>
+            }
+        </>
+    }
+}
@@ -0,0 +1,64 @@
+//! Decks card allows user to choose decks to train on.
+
+use yew::{function_component, html, Callback, Html, Properties};
+
+use crate::custom::{CustomDeckCard, CustomDeckProperties};
+use crate::model::{CustomDeck, Deck};
+
+/// Deck with its state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckEntry {
+    pub deck: Deck,
+    /// Number of words in deck.
+    pub words: usize,
+    pub selected: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct DecksProperties {
+    pub callback: Callback<crate::Msg>,
+    pub entries: Vec<DeckEntry>,
+    pub custom: Option<CustomDeck>,
+}
+
+#[function_component(DecksCard)]
+pub fn decks_card(props: &DecksProperties) -> Html {
+    let custom = CustomDeckProperties {
+        callback: props.callback.clone(),
+        deck: props.custom.clone(),
+    };
+
+    html! {
+        <>
+            {props.entries.iter().map(|entry| render_entry(&props.callback, entry)).collect::<Html>()}
+            <p class="panel-heading deck-heading">{"Загрузить свой список"}</p>
+            <CustomDeckCard ..custom/>
+        </>
+    }
+}
+
+fn render_entry(callback: &Callback<crate::Msg>, entry: &DeckEntry) -> Html {
+    let deck = &entry.deck;
+    let id = deck.id.clone();
+    let selected = entry.selected;
+    let onchange = callback.reform(move |_| crate::Msg::SelectDeck(id.clone(), !selected));
+
+    html! {
+        <label class="panel-block deck-entry">
+            <input type="checkbox" checked={selected} {onchange}/>
+            <div>
+                <p><b>{&deck.title}</b></p>
+                <p>{&deck.description}</p>
+                <p class="deck-meta">
+                    {format!("Слов: {}", entry.words)}
+                    if !deck.author.is_empty() {
+                        {format!(" · Автор: {}", deck.author)}
+                    }
+                    if !deck.version.is_empty() {
+                        {format!(" · Версия: {}", deck.version)}
+                    }
+                </p>
+            </div>
+        </label>
+    }
+}
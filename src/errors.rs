@@ -0,0 +1,77 @@
+//! View of errors and warnings found in word list.
+
+use std::ops::Range;
+
+use yew::{function_component, html, Html, Properties};
+
+use crate::model::{ParseError, ParseWarning};
+
+#[derive(PartialEq, Properties)]
+pub struct ParseErrorsProperties {
+    /// Name of word list.
+    pub file: String,
+    pub errors: Vec<ParseError>,
+    pub warnings: Vec<ParseWarning>,
+}
+
+#[function_component(ParseErrors)]
+pub fn parse_errors(props: &ParseErrorsProperties) -> Html {
+    if props.errors.is_empty() && props.warnings.is_empty() {
+        return html!();
+    }
+    let report = props
+        .errors
+        .iter()
+        .map(|e| e.render(&props.file))
+        .chain(props.warnings.iter().map(|w| w.render(&props.file)))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    html! {
+        <div class="parse-errors">
+            {
+                props.errors.iter().map(|e| {
+                    render_diagnostic("parse-error", e.line, &e.columns, &e.snippet, e.kind.to_string())
+                }).collect::<Html>()
+            }
+            {
+                props.warnings.iter().map(|w| {
+                    render_diagnostic("parse-warning", w.line, &w.columns, &w.snippet, w.kind.to_string())
+                }).collect::<Html>()
+            }
+            <details>
+                <summary>{"Текст сообщений"}</summary>
+                <pre>{report}</pre>
+            </details>
+        </div>
+    }
+}
+
+/// Render message with line it refers to, marking columns of that line.
+fn render_diagnostic(
+    class: &'static str,
+    line: usize,
+    columns: &Range<usize>,
+    snippet: &str,
+    message: String,
+) -> Html {
+    let chars = snippet.chars().collect::<Vec<_>>();
+    let start = (columns.start - 1).min(chars.len());
+    let end = (columns.end - 1).clamp(start, chars.len());
+    let before = chars[..start].iter().collect::<String>();
+    let marked = chars[start..end].iter().collect::<String>();
+    let after = chars[end..].iter().collect::<String>();
+
+    html! {
+        <div class={class}>
+            <p>
+                <b>{format!("Строка {}, столбец {}: ", line, columns.start)}</b>
+                {message}
+            </p>
+            <pre class="parse-error-snippet">
+                <span class="parse-error-line">{line}</span>
+                {before}<mark>{marked}</mark>{after}
+            </pre>
+        </div>
+    }
+}
@@ -1,8 +1,14 @@
 //! Failure card provides user a correct word emphasis.
 
-use yew::{html, Callback, Component, Context, Html, Properties};
+use yew::{classes, context::ContextHandle, html, Callback, Component, Context, Html, Properties};
 
-use crate::model::Word;
+use crate::actions::word_actions;
+use crate::model::{
+    markup::{self, Span},
+    Settings, StressStyle, Stressed, Word, WordHash, WordState,
+};
+use crate::route::Route;
+use crate::stress::stressed;
 
 #[derive(PartialEq, Properties)]
 pub struct FailureProperties {
@@ -10,64 +16,92 @@ pub struct FailureProperties {
     pub word: Word,
     pub seealso: Vec<Word>,
     pub opposite: Vec<Word>,
+    /// Forms of lemma of word, including the word itself.
+    pub paradigm: Vec<Word>,
+    /// Words that explanation refers to, along with text of reference.
+    pub links: Vec<(String, WordHash)>,
+    /// Whether answer may be undone.
+    pub undo: bool,
+    pub state: WordState,
 }
 
 pub enum FailureMsg {
     Continue,
+    Undo,
+    Settings(Settings),
 }
 
-pub struct FailureCard;
+pub struct FailureCard {
+    settings: Settings,
+    _handle: ContextHandle<Settings>,
+}
 
 impl Component for FailureCard {
     type Message = FailureMsg;
     type Properties = FailureProperties;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        FailureCard
+    fn create(ctx: &Context<Self>) -> Self {
+        let (settings, handle) = ctx
+            .link()
+            .context(ctx.link().callback(FailureMsg::Settings))
+            .expect("Settings context is not provided");
+        FailureCard {
+            settings,
+            _handle: handle,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             FailureMsg::Continue => ctx.props().callback.emit(crate::Msg::NextWord),
+            FailureMsg::Undo => ctx.props().callback.emit(crate::Msg::Undo),
+            FailureMsg::Settings(settings) => self.settings = settings,
         }
         true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let on_continue = ctx.link().callback(|_| FailureMsg::Continue);
+        let style = self.settings.stress;
 
         html! {
             <>
+                <div class="panel-block answer-status" role="status">{"✗ Неверно"}</div>
                 <div class="panel-block failure">
-                    <b class="failure-word">{&ctx.props().word}</b>
+                    <b class="failure-word">{stressed(&ctx.props().word.stressed(style))}</b>
                 </div>
-                {seealso(ctx)}
-                {explanation(ctx)}
+                {paradigm(&ctx.props().word, &ctx.props().paradigm, style)}
+                {seealso(&ctx.props().word, &ctx.props().seealso, &ctx.props().opposite, style)}
+                {explanation(&ctx.props().word, &ctx.props().links, style)}
                 <div class="panel-block">
                     <button class="button is-primary is-fullwidth" onclick={on_continue} >
                     {"Продолжить"}
                     </button>
+                    {undo(self, ctx)}
                 </div>
+                {word_actions(&ctx.props().callback, ctx.props().word.hash(), ctx.props().state)}
             </>
         }
     }
 }
 
-fn seealso(ctx: &Context<FailureCard>) -> Html {
-    let seealso = &ctx.props().seealso;
-    let opposite = &ctx.props().opposite;
+/// Render words of the same group as `word`.
+pub fn seealso(word: &Word, seealso: &[Word], opposite: &[Word], style: StressStyle) -> Html {
     if seealso.is_empty() && opposite.is_empty() {
         html! {}
     } else {
         html! {
             <div class="panel-block failure-seealso">
+                if let Some(group) = &word.group {
+                    <p class="failure-group">{format!("Группа {}", group.name)}</p>
+                }
                 {
                     if !seealso.is_empty() {
                         html! {
                             <>
                             <p>{"А также"}</p>
                             <div>
-                                {seealso.iter().map(|w| html!(<p>{w}</p>)).collect::<Html>()}
+                                {seealso.iter().map(|w| html!(<p>{stressed(&w.stressed(style))}</p>)).collect::<Html>()}
                             </div>
                             </>
                         }
@@ -81,7 +115,7 @@ fn seealso(ctx: &Context<FailureCard>) -> Html {
                             <>
                             <p>{"Но"}</p>
                             <div>
-                                {opposite.iter().map(|w| html!(<p>{w}</p>)).collect::<Html>()}
+                                {opposite.iter().map(|w| html!(<p>{stressed(&w.stressed(style))}</p>)).collect::<Html>()}
                             </div>
                             </>
                         }
@@ -94,12 +128,52 @@ fn seealso(ctx: &Context<FailureCard>) -> Html {
     }
 }
 
-fn explanation(ctx: &Context<FailureCard>) -> Html {
-    if let Some(explanation) = &ctx.props().word.explanation {
+/// Render forms of lemma of `word` along with their names.
+pub fn paradigm(word: &Word, paradigm: &[Word], style: StressStyle) -> Html {
+    if paradigm.is_empty() {
+        return html! {};
+    }
+    let (lemma, emphasis) = word.lemma();
+    html! {
+        <div class="panel-block failure-seealso failure-paradigm">
+            <p class="failure-group">{"Формы слова "}{stressed(&Stressed::new(lemma, emphasis, style))}</p>
+            <div>
+                {paradigm.iter().map(|w| html! {
+                    <p class={classes!((w == word).then_some("is-current"))}>
+                        {stressed(&w.stressed(style))}
+                        if let Some(name) = w.form.as_ref().and_then(|form| form.name.as_ref()) {
+                            <span class="failure-form">{format!(" {name}")}</span>
+                        }
+                    </p>
+                }).collect::<Html>()}
+            </div>
+        </div>
+    }
+}
+
+fn undo(card: &FailureCard, ctx: &Context<FailureCard>) -> Html {
+    if ctx.props().undo && card.settings.undo {
+        let onclick = ctx.link().callback(|_| FailureMsg::Undo);
+        html!(<button class="button is-warning is-outlined" {onclick}>{"Отменить"}</button>)
+    } else {
+        html!()
+    }
+}
+
+/// Render explanation of word's emphasis if it has one.
+///
+/// References to other words found in `links` lead to their pages in word list.
+pub fn explanation(word: &Word, links: &[(String, WordHash)], style: StressStyle) -> Html {
+    if let Some(explanation) = &word.explanation {
         html! {
             <div class="panel-block failure-explanation">
                 <div>
-                    <p>{explanation}</p>
+                    {
+                        markup::parse(explanation)
+                            .into_iter()
+                            .map(|paragraph| html!(<p>{paragraph.into_iter().map(|span| render_span(span, links, style)).collect::<Html>()}</p>))
+                            .collect::<Html>()
+                    }
                 </div>
             </div>
         }
@@ -107,3 +181,25 @@ fn explanation(ctx: &Context<FailureCard>) -> Html {
         html!()
     }
 }
+
+/// Render part of explanation.
+fn render_span(span: Span, links: &[(String, WordHash)], style: StressStyle) -> Html {
+    match span {
+        Span::Text(text) => html!({ text }),
+        Span::Emphasis(text) => html!(<em>{text}</em>),
+        Span::Stressed(word, pos) => {
+            // Explanation is written with uppercase letters already, so they are highlighted instead.
+            let style = match style {
+                StressStyle::Uppercase => StressStyle::Highlight,
+                style => style,
+            };
+            stressed(&Stressed::new(&word.to_lowercase(), pos, style))
+        }
+        Span::Link(text) => match links.iter().find(|(link, _)| *link == text) {
+            Some((_, hash)) => html! {
+                <a class="explanation-link" href={Route::Word(*hash).href()}>{text}</a>
+            },
+            None => html!(<span class="explanation-link is-missing">{text}</span>),
+        },
+    }
+}
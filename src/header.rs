@@ -2,37 +2,56 @@
 
 use std::time::Duration;
 
-use yew::{function_component, html, Properties};
+use yew::{function_component, html, use_context, Properties};
+
+use crate::model::Settings;
 
 #[derive(PartialEq, Properties)]
 pub struct HeaderProperties {
     pub time: Duration,
     pub words: u32,
+    pub answered: u32,
 }
 
 #[function_component(CardHeader)]
 pub fn card_header(props: &HeaderProperties) -> Html {
-    let time = props.time;
-    let hours = time.as_secs() / 3600;
-    let minutes = time.as_secs() % 3600 / 60;
-    let seconds = time.as_secs() % 60;
-    let formatted = if hours == 0 {
-        format!("{:02}:{:02}", minutes, seconds)
+    let settings = use_context::<Settings>().unwrap_or_default();
+
+    let progress = if settings.session_length > 0 {
+        html!(<span>{format!("{}/{}", props.answered, settings.session_length)}</span>)
     } else {
-        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+        html!()
+    };
+    let time = if settings.timer {
+        html!(<span>{format_time(props.time)}</span>)
+    } else {
+        html!()
     };
 
     html! {
         <div class={format!("is-flex panel-heading")}>
             <span>{props.words}{" "}{words_ending(props.words)}</span>
-            <span>{formatted}</span>
+            {progress}
+            {time}
         </div>
     }
 }
 
+/// Format time as `MM:SS` or `HH:MM:SS` if it is longer than an hour.
+pub fn format_time(time: Duration) -> String {
+    let hours = time.as_secs() / 3600;
+    let minutes = time.as_secs() % 3600 / 60;
+    let seconds = time.as_secs() % 60;
+    if hours == 0 {
+        format!("{:02}:{:02}", minutes, seconds)
+    } else {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+}
+
 fn words_ending(num: u32) -> &'static str {
     let ending = num % 10;
-    if num >= 5 && num < 20 {
+    if (5..20).contains(&num) {
         "слов"
     } else {
         match ending {
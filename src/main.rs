@@ -1,40 +1,112 @@
 use std::time::Duration;
 
 use gloo::console;
-use gloo::timers::callback::Interval;
-use model::{CardResult, Model, ParseError, Variant, Word};
+use gloo::events::EventListener;
+use gloo::timers::callback::{Interval, Timeout};
+use gloo::utils::{document, window};
+use model::{
+    CardResult, CustomDeck, Deck, Diagnostics, Model, ParseError, ParseWarning, Settings, Snapshot,
+    Theme, Variant, Word, WordHash,
+};
 use question::QuestionCard;
+use yew::context::ContextProviderProps;
 use yew::prelude::*;
 
+use crate::dashboard::{Dashboard, DashboardProperties};
+use crate::decks::{DeckEntry, DecksCard, DecksProperties};
 use crate::failure::{FailureCard, FailureProperties};
 use crate::header::CardHeader;
 use crate::question::QuestionProperties;
+use crate::route::Route;
+use crate::settings::{SettingsCard, SettingsProperties};
+use crate::shared::{SharedCard, SharedProperties};
+use crate::success::{SuccessCard, SuccessProperties};
+use crate::summary::{SummaryCard, SummaryProperties};
+use crate::words::{WordCard, WordEntry, WordList, WordListProperties, WordProperties};
 
+mod actions;
+mod custom;
+mod dashboard;
+mod decks;
+mod errors;
 mod failure;
 mod header;
 mod model;
 mod question;
-mod util;
+mod route;
+mod settings;
+mod shared;
+mod stress;
+mod success;
+mod summary;
+mod text;
+mod words;
 
 pub enum Msg {
     Tick,
     Success(Word),
     Failure(Word),
+    Skip(Word),
     NextWord,
+    Undo,
+    UndoExpired,
+    Restart,
+    /// Train specified word right now.
+    Practice(WordHash),
+    /// Exclude word from training or return it back.
+    Suspend(WordHash, bool),
+    /// Exclude word from training until tomorrow.
+    Bury(WordHash),
+    /// Mark word as one with possibly incorrect data or remove the mark.
+    Flag(WordHash, bool),
+    Route(Route),
+    Settings(Settings),
+    /// Include deck into training or exclude it.
+    SelectDeck(String, bool),
+    /// Replace or remove custom deck.
+    CustomDeck(Option<CustomDeck>),
 }
 
+/// How long undo is available after correct answer, in milliseconds.
+const UNDO_TIMEOUT: u32 = 5000;
+
 struct App {
     model: Model,
+    settings: Settings,
+    custom: Option<CustomDeck>,
+    /// All decks with number of their words, counted on reload.
+    decks: Vec<(Deck, usize)>,
     header_color: &'static str,
     time: Duration,
+    /// Number of correctly answered words.
+    words: u32,
+    /// Number of answered words.
+    answered: u32,
+    stage: Stage,
+    route: Route,
+    _route_listener: EventListener,
+    undo: Option<Undo>,
+    undo_timeout: Option<Timeout>,
+}
+
+/// State required to revert the last answer.
+#[derive(Debug, Clone)]
+struct Undo {
+    snapshot: Snapshot,
     words: u32,
+    answered: u32,
     stage: Stage,
 }
 
 #[derive(Debug, Clone)]
 enum Stage {
     Question(Word, Vec<Variant>),
+    Success(Word),
     Failure(Word),
+    /// Session is over.
+    Finished,
+    /// There are no words to train.
+    Empty,
 }
 
 impl Component for App {
@@ -47,16 +119,36 @@ impl Component for App {
             callback.emit(());
         })
         .forget();
-        let (mut model, errors) = Model::new();
-        log_errors(errors);
-        let word = model.next();
-        let variants = word.variants();
+        let callback = ctx.link().callback(|_: ()| Msg::Route(Route::current()));
+        let route_listener = EventListener::new(&window(), "hashchange", move |_| {
+            callback.emit(());
+        });
+        let settings = Settings::load();
+        let custom = CustomDeck::load();
+        let (mut model, errors, warnings) = Model::new(&selected_decks(&settings, custom.as_ref()));
+        log_diagnostics(errors, warnings);
+        apply_theme(settings.theme);
+        let stage = match model.next(settings.order) {
+            Some(word) => {
+                let variants = word.variants();
+                Stage::Question(word, variants)
+            }
+            None => Stage::Empty,
+        };
         App {
             model,
+            settings,
+            decks: count_words(custom.as_ref()),
+            custom,
             header_color: "",
             time: Duration::ZERO,
             words: 0,
-            stage: Stage::Question(word, variants),
+            answered: 0,
+            stage,
+            route: Route::current(),
+            _route_listener: route_listener,
+            undo: None,
+            undo_timeout: None,
         }
     }
 
@@ -66,20 +158,100 @@ impl Component for App {
                 self.time += Duration::from_secs(1);
             }
             Msg::Failure(word) => {
-                self.model.stats.passed(word.hash(), CardResult::Failed);
+                self.undo_timeout = None;
+                self.undo = self.pass(&word, CardResult::Failed);
+                self.answered += 1;
+                self.header_color = "is-danger";
+                self.stage = Stage::Failure(word);
+            }
+            Msg::Skip(word) => {
+                self.undo_timeout = None;
+                self.undo = None;
+                self.answered += 1;
                 self.header_color = "is-danger";
                 self.stage = Stage::Failure(word);
             }
             Msg::Success(word) => {
-                self.model.stats.passed(word.hash(), CardResult::Solved);
+                self.undo = self.pass(&word, CardResult::Solved);
                 self.words += 1;
-                ctx.link().send_message(Msg::NextWord);
+                self.answered += 1;
+                if self.settings.success_card {
+                    self.header_color = "is-success";
+                    self.stage = Stage::Success(word);
+                } else {
+                    self.next_word();
+                    let callback = ctx.link().callback(|_: ()| Msg::UndoExpired);
+                    self.undo_timeout = Some(Timeout::new(UNDO_TIMEOUT, move || callback.emit(())));
+                }
             }
             Msg::NextWord => {
-                self.header_color = "";
-                let word = self.model.next();
-                let variants = word.variants();
-                self.stage = Stage::Question(word, variants);
+                self.undo = None;
+                self.undo_timeout = None;
+                self.next_word();
+            }
+            Msg::Undo => {
+                self.undo_timeout = None;
+                if let Some(undo) = self.undo.take() {
+                    self.model.stats.revert(undo.snapshot);
+                    self.words = undo.words;
+                    self.answered = undo.answered;
+                    self.header_color = "";
+                    self.stage = undo.stage;
+                }
+            }
+            Msg::UndoExpired => {
+                self.undo_timeout = None;
+                self.undo = None;
+            }
+            Msg::Restart => {
+                self.undo = None;
+                self.undo_timeout = None;
+                self.time = Duration::ZERO;
+                self.words = 0;
+                self.answered = 0;
+                self.next_word();
+            }
+            Msg::Practice(hash) => {
+                if let Some(word) = self.model.word(hash).cloned() {
+                    self.undo = None;
+                    self.undo_timeout = None;
+                    self.header_color = "";
+                    let variants = word.variants();
+                    self.stage = Stage::Question(word, variants);
+                    Route::Trainer.push();
+                }
+            }
+            Msg::Suspend(hash, suspended) => {
+                self.model.stats.set_suspended(hash, suspended);
+                self.refresh_question(hash);
+            }
+            Msg::Bury(hash) => {
+                self.model.stats.bury(hash);
+                self.refresh_question(hash);
+            }
+            Msg::Flag(hash, flagged) => self.model.stats.set_flagged(hash, flagged),
+            Msg::Route(route) => self.route = route,
+            Msg::Settings(settings) => {
+                apply_theme(settings.theme);
+                settings.save();
+                let reload = settings.selected_decks != self.settings.selected_decks;
+                self.settings = settings;
+                if reload {
+                    self.reload();
+                }
+            }
+            Msg::SelectDeck(id, selected) => {
+                self.select_deck(id, selected);
+                self.reload();
+            }
+            Msg::CustomDeck(deck) => {
+                match &deck {
+                    Some(deck) => deck.save(),
+                    None => CustomDeck::delete(),
+                }
+                self.select_deck(String::from(Deck::CUSTOM), deck.is_some());
+                self.custom = deck;
+                self.reload();
             }
         }
         true
@@ -88,36 +260,110 @@ impl Component for App {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let callback = ctx.link().callback(|msg| msg);
 
-        let card = match self.stage.clone() {
-            Stage::Question(word, variants) => {
-                let props = QuestionProperties {
+        let (header, card) = match &self.route {
+            Route::Trainer => {
+                let header = html! {
+                    <CardHeader time={self.time} words={self.words} answered={self.answered}/>
+                };
+                (header, self.trainer(ctx))
+            }
+            Route::Dashboard => {
+                let props = DashboardProperties {
+                    progress: self.model.progress(),
+                    groups: self.model.group_progress(),
+                };
+                (heading("Статистика"), html!(<Dashboard ..props/>))
+            }
+            Route::Words => {
+                let entries = self
+                    .model
+                    .words()
+                    .map(|word| WordEntry {
+                        word: word.clone(),
+                        group: self.model.stats.group(word.hash()).unwrap_or_default(),
+                        state: self.model.stats.state(word.hash()),
+                    })
+                    .collect();
+                let props = WordListProperties {
                     callback,
-                    word,
-                    variants,
+                    entries,
+                    report: self.model.flagged_report(self.settings.stress),
                 };
-                html!(<QuestionCard ..props/>)
+                (heading("Слова"), html!(<WordList ..props/>))
             }
-            Stage::Failure(word) => {
-                let (seealso, opposite) = if word.group.is_some() {
-                    (self.model.seealso(&word), self.model.opposite(&word))
-                } else {
-                    (Vec::new(), Vec::new())
+            &Route::Word(hash) => {
+                let card = match self.model.word(hash).cloned() {
+                    Some(word) => {
+                        let (seealso, opposite) = self.related(&word);
+                        let props = WordProperties {
+                            callback,
+                            group: self.model.stats.group(hash).unwrap_or_default(),
+                            state: self.model.stats.state(hash),
+                            links: self.model.links(&word),
+                            paradigm: self.model.paradigm(&word),
+                            word,
+                            seealso,
+                            opposite,
+                        };
+                        html!(<WordCard ..props/>)
+                    }
+                    None => html! {
+                        <div class="panel-block">
+                            <p>{"Слово не найдено."}</p>
+                        </div>
+                    },
                 };
-                let props = FailureProperties {
+                (heading("Слово"), card)
+            }
+            Route::Decks => {
+                let entries = self
+                    .decks
+                    .iter()
+                    .map(|(deck, words)| DeckEntry {
+                        words: *words,
+                        selected: self.settings.selected_decks.contains(&deck.id),
+                        deck: deck.clone(),
+                    })
+                    .collect();
+                let props = DecksProperties {
                     callback,
-                    word,
-                    seealso,
-                    opposite,
+                    entries,
+                    custom: self.custom.clone(),
                 };
-                html!(<FailureCard ..props/>)
+                (heading("Наборы слов"), html!(<DecksCard ..props/>))
+            }
+            Route::Shared(payload) => {
+                let props = SharedProperties {
+                    callback,
+                    payload: payload.clone(),
+                    replaces: self.custom.is_some(),
+                };
+                (heading("Список по ссылке"), html!(<SharedCard ..props/>))
+            }
+            Route::Settings => {
+                let props = SettingsProperties { callback };
+                (heading("Настройки"), html!(<SettingsCard ..props/>))
             }
         };
+        let header_color = match &self.route {
+            Route::Trainer => self.header_color,
+            _ => "",
+        };
 
-        html! {
+        let content = html! {
             <>
+                <nav class="tabs is-centered">
+                    <ul>
+                        {tab(&self.route, Route::Trainer, "Тренажёр")}
+                        {tab(&self.route, Route::Dashboard, "Статистика")}
+                        {tab(&self.route, Route::Words, "Слова")}
+                        {tab(&self.route, Route::Decks, "Наборы")}
+                        {tab(&self.route, Route::Settings, "Настройки")}
+                    </ul>
+                </nav>
                 <main>
-                    <section class={format!("mycard panel {}", self.header_color)}>
-                        <CardHeader time={self.time} words={self.words}/>
+                    <section class={format!("mycard panel {}", header_color)}>
+                        {header}
                         {card}
                     </section>
                 </main>
@@ -134,21 +380,222 @@ impl Component for App {
                     </div>
                 </footer>
             </>
+        };
+        let props = ContextProviderProps {
+            context: self.settings.clone(),
+            children: Children::new(vec![content]),
+        };
+        html!(<ContextProvider<Settings> ..props/>)
+    }
+}
+
+impl App {
+    /// Recreate model with words of currently selected decks.
+    fn reload(&mut self) {
+        let (model, errors, warnings) =
+            Model::new(&selected_decks(&self.settings, self.custom.as_ref()));
+        log_diagnostics(errors, warnings);
+        self.model = model;
+        self.decks = count_words(self.custom.as_ref());
+        self.undo = None;
+        self.undo_timeout = None;
+        self.next_word();
+    }
+
+    /// Include deck into training or exclude it, saving settings.
+    fn select_deck(&mut self, id: String, selected: bool) {
+        let decks = &mut self.settings.selected_decks;
+        decks.retain(|d| *d != id);
+        if selected {
+            decks.push(id);
+        }
+        self.settings.save();
+    }
+
+    /// Render card of current trainer stage.
+    fn trainer(&self, ctx: &Context<Self>) -> Html {
+        let callback = ctx.link().callback(|msg| msg);
+
+        match self.stage.clone() {
+            Stage::Question(word, variants) => {
+                let props = QuestionProperties {
+                    callback,
+                    state: self.model.stats.state(word.hash()),
+                    word,
+                    variants,
+                    undo: self.undo.is_some(),
+                };
+                html!(<QuestionCard ..props/>)
+            }
+            Stage::Success(word) => {
+                let (seealso, opposite) = self.related(&word);
+                let props = SuccessProperties {
+                    callback,
+                    links: self.model.links(&word),
+                    paradigm: self.model.paradigm(&word),
+                    word,
+                    seealso,
+                    opposite,
+                    undo: self.undo.is_some(),
+                };
+                html!(<SuccessCard ..props/>)
+            }
+            Stage::Failure(word) => {
+                let (seealso, opposite) = self.related(&word);
+                let props = FailureProperties {
+                    callback,
+                    links: self.model.links(&word),
+                    paradigm: self.model.paradigm(&word),
+                    state: self.model.stats.state(word.hash()),
+                    word,
+                    seealso,
+                    opposite,
+                    undo: self.undo.is_some(),
+                };
+                html!(<FailureCard ..props/>)
+            }
+            Stage::Finished => {
+                let props = SummaryProperties {
+                    callback,
+                    time: self.time,
+                    words: self.words,
+                    answered: self.answered,
+                };
+                html!(<SummaryCard ..props/>)
+            }
+            Stage::Empty => html! {
+                <div class="panel-block summary">
+                    <p>{"Нет слов для тренировки."}</p>
+                    <a href={Route::Decks.href()}>{"Выбрать наборы слов"}</a>
+                    <a href={Route::Words.href()}>{"Вернуть скрытые слова"}</a>
+                </div>
+            },
+        }
+    }
+}
+
+impl App {
+    /// Show new question or finish the session if it is over.
+    fn next_word(&mut self) {
+        self.header_color = "";
+        let length = self.settings.session_length;
+        if length > 0 && self.answered >= length {
+            self.stage = Stage::Finished;
+            return;
         }
+        self.stage = match self.model.next(self.settings.order) {
+            Some(word) => {
+                let variants = word.variants();
+                Stage::Question(word, variants)
+            }
+            None => Stage::Empty,
+        };
+    }
+
+    /// Show another question if state of word change made current question or its absence outdated.
+    fn refresh_question(&mut self, changed: WordHash) {
+        let state = self.model.stats.state(changed);
+        let outdated = match &self.stage {
+            Stage::Question(word, _) => word.hash() == changed && (state.suspended || state.buried),
+            Stage::Empty => !state.suspended && !state.buried,
+            _ => false,
+        };
+        if outdated {
+            self.next_word();
+        }
+    }
+
+    /// Get words with the same group and rule, and words with the same group but opposite rule.
+    fn related(&self, word: &Word) -> (Vec<Word>, Vec<Word>) {
+        if word.group.is_some() {
+            (self.model.seealso(word), self.model.opposite(word))
+        } else {
+            (Vec::new(), Vec::new())
+        }
+    }
+
+    /// Save result of answer to statistics, remembering state required to undo it.
+    fn pass(&mut self, word: &Word, result: CardResult) -> Option<Undo> {
+        let words = self.words;
+        let answered = self.answered;
+        let stage = self.stage.clone();
+        self.model
+            .stats
+            .passed(word.hash(), result)
+            .map(|snapshot| Undo {
+                snapshot,
+                words,
+                answered,
+                stage,
+            })
     }
 }
 
-fn log_errors(errors: Vec<ParseError>) {
+/// Get decks selected in settings.
+fn selected_decks(settings: &Settings, custom: Option<&CustomDeck>) -> Vec<Deck> {
+    Deck::builtin()
+        .into_iter()
+        .chain(custom.map(CustomDeck::deck))
+        .filter(|deck| settings.selected_decks.contains(&deck.id))
+        .collect()
+}
+
+/// Get all decks along with number of their words.
+fn count_words(custom: Option<&CustomDeck>) -> Vec<(Deck, usize)> {
+    Deck::builtin()
+        .into_iter()
+        .chain(custom.map(CustomDeck::deck))
+        .map(|deck| {
+            let words = deck.words().0.len();
+            (deck, words)
+        })
+        .collect()
+}
+
+/// Render title of card.
+fn heading(title: &'static str) -> Html {
+    html!(<p class="panel-heading">{title}</p>)
+}
+
+/// Render navigation tab, highlighting it if it is active.
+fn tab(current: &Route, route: Route, title: &'static str) -> Html {
+    let active = match current {
+        Route::Word(_) => route == Route::Words,
+        Route::Shared(_) => route == Route::Decks,
+        current => *current == route,
+    };
+    html! {
+        <li class={classes!(active.then_some("is-active"))}>
+            <a href={route.href()}>{title}</a>
+        </li>
+    }
+}
+
+/// Set color theme of the document.
+fn apply_theme(theme: Theme) {
+    if let Some(root) = document().document_element() {
+        let _ = root.set_attribute("data-theme", theme.attribute());
+    }
+}
+
+fn log_diagnostics(errors: Diagnostics<ParseError>, warnings: Diagnostics<ParseWarning>) {
     match errors.len() {
         0 => console::log!("Word data loaded with no errors."),
         n => {
             console::group!(collapsed format!("Word data loaded with {n} errors."));
-            for error in errors {
-                console::error!(error.to_string());
+            for (deck, error) in errors {
+                console::error!(error.render(&deck));
             }
             console::group_end!();
         }
     }
+    if !warnings.is_empty() {
+        console::group!(collapsed format!("Word data has {} warnings.", warnings.len()));
+        for (deck, warning) in warnings {
+            console::warn!(warning.render(&deck));
+        }
+        console::group_end!();
+    }
 }
 
 fn main() {
@@ -0,0 +1,27 @@
+// Decks compiled into application, by id. Path of word list is relative to this file.
+//
+// This file isn't a module: it is included both by `deck.rs` and by build script,
+// which define `builtin_decks!` to build decks and to check their word lists respectively.
+builtin_decks! {
+    "ege" => {
+        path: "data.txt",
+        title: "Орфоэпический словник ЕГЭ",
+        description: "Слова из орфоэпического словника ФИПИ для задания 4 ЕГЭ по русскому языку.",
+        author: "ClayenKitten",
+        version: "2022",
+    },
+    "extended" => {
+        path: "extended.txt",
+        title: "Расширенный список",
+        description: "Часто встречающиеся слова, которых нет в словнике ЕГЭ.",
+        author: "ClayenKitten",
+        version: "1",
+    },
+    "names" => {
+        path: "names.txt",
+        title: "Имена и названия",
+        description: "Имена, фамилии и географические названия.",
+        author: "ClayenKitten",
+        version: "1",
+    },
+}
@@ -0,0 +1,65 @@
+use gloo::storage::{errors::StorageError, LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+use super::{Deck, Format, Notation, ProgressScope};
+
+/// Word list provided by user in the same syntax as built-in one, or imported from table or JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomDeck {
+    /// Name of file the list was loaded from.
+    pub name: String,
+    /// Source text of word list.
+    pub source: String,
+    /// Format of source text. Lists saved before import was supported are in `data.txt` syntax.
+    #[serde(default)]
+    pub format: Format,
+    /// Notation of stressed letter chosen by user.
+    #[serde(default)]
+    pub notation: Notation,
+}
+
+impl CustomDeck {
+    const KEY: &'static str = "custom-deck";
+
+    /// Load custom deck from LocalStorage if it was saved.
+    pub fn load() -> Option<Self> {
+        match LocalStorage::get(Self::KEY) {
+            Ok(deck) => Some(deck),
+            Err(StorageError::KeyNotFound(_) | StorageError::SerdeError(_)) => None,
+            Err(StorageError::JsError(e)) => {
+                gloo::console::error!(format!("LocalStorage is not functional {e}"));
+                None
+            }
+        }
+    }
+
+    /// Save custom deck to LocalStorage.
+    pub fn save(&self) {
+        if let Err(e) = LocalStorage::set(Self::KEY, self) {
+            gloo::console::error!(format!("Failed to save custom deck: {e}"));
+        }
+    }
+
+    /// Get deck with words of custom list.
+    ///
+    /// Progress on its words is never shared with built-in decks.
+    pub fn deck(&self) -> Deck {
+        Deck {
+            id: String::from(Deck::CUSTOM),
+            title: self.name.clone(),
+            description: String::from("Список, загруженный из файла."),
+            author: String::new(),
+            version: String::new(),
+            source: self.source.clone(),
+            format: self.format,
+            notation: self.notation,
+            preparsed: None,
+            progress: ProgressScope::Deck,
+        }
+    }
+
+    /// Remove custom deck from LocalStorage.
+    pub fn delete() {
+        LocalStorage::delete(Self::KEY);
+    }
+}
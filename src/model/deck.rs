@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+
+use super::{import, Format, Notation, ParseError, ParseWarning, Word};
+
+/// Get words of built-in deck parsed by build script, if `preparsed` feature is enabled.
+#[cfg(feature = "preparsed")]
+macro_rules! preparsed {
+    ($id:literal) => {
+        Some(include_str!(concat!(env!("OUT_DIR"), "/", $id, ".json")))
+    };
+}
+
+#[cfg(not(feature = "preparsed"))]
+macro_rules! preparsed {
+    ($id:literal) => {
+        None
+    };
+}
+
+/// Named list of words with metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    /// Unique identifier of deck. It is stored in settings and must not change.
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub author: String,
+    pub version: String,
+    /// Source text of word list.
+    pub source: String,
+    /// Format of source text.
+    pub format: Format,
+    /// Notation of stressed letter in source text.
+    pub notation: Notation,
+    /// Words parsed at build time, serialized to JSON.
+    pub preparsed: Option<&'static str>,
+    /// Whether progress on words of this deck is shared with other decks.
+    pub progress: ProgressScope,
+}
+
+impl Deck {
+    /// Identifier of deck loaded by user.
+    pub const CUSTOM: &'static str = "custom";
+
+    /// Get all decks compiled into application.
+    pub fn builtin() -> Vec<Deck> {
+        macro_rules! builtin_decks {
+            ($($id:literal => {
+                path: $path:literal,
+                title: $title:literal,
+                description: $description:literal,
+                author: $author:literal,
+                version: $version:literal $(,)?
+            }),* $(,)?) => {
+                vec![$(Deck {
+                    id: String::from($id),
+                    title: String::from($title),
+                    description: String::from($description),
+                    author: String::from($author),
+                    version: String::from($version),
+                    format: Format::from_name($path),
+                    notation: Notation::Uppercase,
+                    source: String::from(include_str!($path)),
+                    preparsed: preparsed!($id),
+                    progress: ProgressScope::Shared,
+                }),*]
+            };
+        }
+        include!("builtin_decks.rs")
+    }
+
+    /// Parse words of deck.
+    ///
+    /// Hashes of words are scoped to deck if its progress isn't shared.
+    /// Pre-parsed words are checked at build time, so they have no errors and warnings.
+    pub fn words(&self) -> (Vec<Word>, Vec<ParseError>, Vec<ParseWarning>) {
+        let (words, errors, warnings) = match self.preparsed {
+            Some(json) => {
+                let words = serde_json::from_str(json).expect("pre-parsed words are valid");
+                (words, Vec::new(), Vec::new())
+            }
+            None => import(&self.source, self.format, self.notation),
+        };
+        let words = match self.progress {
+            ProgressScope::Shared => words,
+            ProgressScope::Deck => words.into_iter().map(|w| w.scoped(&self.id)).collect(),
+        };
+        (words, errors, warnings)
+    }
+}
+
+/// Rule that defines whether the same word in two decks shares progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProgressScope {
+    /// Word shares progress with the same word in all other decks with shared progress.
+    Shared,
+    /// Progress on word is tracked for this deck only.
+    Deck,
+}
+
+#[cfg(test)]
+mod test {
+    use super::Deck;
+
+    #[test]
+    fn test_builtin_decks_loaded_correctly() {
+        for deck in Deck::builtin() {
+            let (words, errors, _) = deck.words();
+            assert!(!words.is_empty(), "Deck `{}` is empty", deck.id);
+            assert!(
+                errors.is_empty(),
+                "Deck `{}` has errors: {errors:?}",
+                deck.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_extended_deck_has_no_exam_words() {
+        let decks = Deck::builtin();
+        let deck = |id| decks.iter().find(|d| d.id == id).unwrap().words().0;
+        let exam = deck("ege");
+        for word in deck("extended") {
+            assert!(
+                exam.iter().all(|w| w.inner() != word.inner()),
+                "`{word}` is already in exam deck"
+            );
+        }
+    }
+
+    #[test]
+    fn test_builtin_deck_ids_unique() {
+        let decks = Deck::builtin();
+        for (i, deck) in decks.iter().enumerate() {
+            assert!(decks[i + 1..].iter().all(|d| d.id != deck.id));
+            assert_ne!(deck.id, Deck::CUSTOM);
+        }
+    }
+}
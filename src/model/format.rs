@@ -0,0 +1,311 @@
+//! Representation of word list that may be edited and written back.
+//!
+//! Words are written back as they were written, in notation of the list,
+//! along with comments, sections and explanation tags. Only spacing is not kept.
+
+use std::fmt::Display;
+
+use crate::text;
+
+use super::{
+    notation::Notation,
+    parse::ParseError,
+    stress::{StressStyle, Stressed},
+    syntax::{ExplanationRef, GroupRef, LemmaRef, Line, WordLine},
+    Word,
+};
+
+/// Word list as it is written, with comments, sections and explanation tags.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    pub lines: Vec<Line>,
+}
+
+impl Document {
+    /// Parse syntax of word list.
+    ///
+    /// Unlike [parse](super::parse), fails if any line can't be parsed, because it can't be written back.
+    /// Stress is expected in `notation` and is written back as it was written.
+    pub fn parse(s: &str, notation: Notation) -> Result<Self, Vec<ParseError>> {
+        let mut lines = Vec::new();
+        let mut errors = Vec::new();
+        for (index, raw) in s.lines().enumerate() {
+            match Line::parse(raw, notation) {
+                Ok(line) => lines.push(line),
+                Err((kind, span)) => errors.push(ParseError::new(index + 1, raw, span, kind)),
+            }
+        }
+        match errors.is_empty() {
+            true => Ok(Document { lines }),
+            false => Err(errors),
+        }
+    }
+
+    /// Create document that defines provided words, writing stressed letters in `style`.
+    ///
+    /// Explanations are written inline and sections are started whenever category of word changes.
+    /// Groups keep their names, so words of the same group are still related.
+    pub fn from_words(words: &[Word], style: StressStyle) -> Self {
+        let mut lines = Vec::new();
+        let mut category = None;
+        for word in words {
+            if word.category.is_some() && word.category != category {
+                category = word.category.clone();
+                if !lines.is_empty() {
+                    lines.push(Line::Blank);
+                }
+                lines.push(Line::Section(category.clone().unwrap_or_default()));
+            }
+            let mut line = WordLine::from(word);
+            line.written = Stressed::new(word.inner(), word.emphasis, style)
+                .with_secondary(&word.secondary)
+                .to_string();
+            if let (Some(lemma), Some(form)) = (&mut line.lemma, &word.form) {
+                lemma.written = Stressed::new(&form.lemma, form.emphasis, style).to_string();
+            }
+            lines.push(Line::Word(line));
+            let explanation = word
+                .explanation
+                .iter()
+                .flat_map(|text| text.lines().skip(1));
+            lines.extend(explanation.map(|text| Line::Continuation(text.to_string())));
+        }
+        Document { lines }
+    }
+
+    /// Write document in canonical form.
+    ///
+    /// Consecutive words are sorted along with continuations of their explanations
+    /// and their columns are aligned,
+    /// sections are separated by one blank line and extra blank lines are removed.
+    pub fn format(&self) -> String {
+        let mut out: Vec<String> = Vec::new();
+        let mut run: Vec<Entry> = Vec::new();
+        for line in &self.lines {
+            match (line, run.last_mut()) {
+                (Line::Word(word), _) => {
+                    run.push((word, Vec::new()));
+                    continue;
+                }
+                (Line::Continuation(text), Some((_, continuations))) => {
+                    continuations.push(text);
+                    continue;
+                }
+                _ => {}
+            }
+            out.extend(format_words(&mut run));
+            match line {
+                Line::Blank if out.last().is_none_or(String::is_empty) => {}
+                Line::Section(_) if out.last().is_some_and(|last| !last.is_empty()) => {
+                    out.push(String::new());
+                    out.push(line.to_string());
+                }
+                Line::Comment(text) if !text.trim().is_empty() => {
+                    out.push(format!("// {}", text.trim()));
+                }
+                _ => out.push(line.to_string()),
+            }
+        }
+        out.extend(format_words(&mut run));
+        while out.last().is_some_and(String::is_empty) {
+            out.pop();
+        }
+        out.into_iter().map(|line| line + "\n").collect()
+    }
+}
+
+/// Word line with continuations of its explanation.
+type Entry<'a> = (&'a WordLine, Vec<&'a String>);
+
+/// Sort words and align their columns, clearing `run`.
+///
+/// Continuations are aligned with explanation of their word.
+fn format_words(run: &mut Vec<Entry>) -> Vec<String> {
+    run.sort_by_cached_key(|(word, _)| (sort_key(&word.word), word.head()));
+    let width = |column: fn(&WordLine) -> Option<String>| {
+        run.iter()
+            .filter_map(|(word, _)| column(word))
+            .map(|text| text::len(&text))
+            .max()
+            .unwrap_or(0)
+    };
+    let head_width = width(|word| Some(word.head()));
+    let group_width = width(WordLine::group);
+
+    let lines = run
+        .iter()
+        .flat_map(|(word, continuations)| {
+            let mut line = pad(&word.head(), head_width);
+            if word.group.is_some() || word.explanation.is_some() {
+                line.push(' ');
+                line.push_str(&pad(&word.group().unwrap_or_default(), group_width));
+            }
+            if let Some(explanation) = word.explanation() {
+                line.push(' ');
+                line.push_str(&explanation);
+            }
+            let indent = " ".repeat(head_width + group_width + 2);
+            let continuations = continuations
+                .iter()
+                .map(move |text| format!("{indent}| {text}").trim_end().to_string());
+            std::iter::once(line.trim_end().to_string()).chain(continuations)
+        })
+        .collect();
+    run.clear();
+    lines
+}
+
+/// Key that sorts words alphabetically.
+fn sort_key(word: &str) -> String {
+    word.to_lowercase().replace('ё', "е")
+}
+
+/// Pad string with spaces to `width` letters.
+fn pad(s: &str, width: usize) -> String {
+    let len = text::len(s);
+    format!("{s}{}", " ".repeat(width.saturating_sub(len)))
+}
+
+impl Display for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&Word> for WordLine {
+    fn from(word: &Word) -> Self {
+        let stressed = text::uppercase_letter(word.inner(), word.emphasis);
+        WordLine {
+            word: stressed.clone(),
+            written: stressed,
+            detail: word.detail.clone(),
+            lemma: word.form.as_ref().map(|form| {
+                let stressed = text::uppercase_letter(&form.lemma, form.emphasis);
+                LemmaRef {
+                    word: stressed.clone(),
+                    written: stressed,
+                    form: form.name.clone(),
+                }
+            }),
+            group: word.group.as_ref().map(|group| GroupRef {
+                name: group.name.clone(),
+                inverted: group.inverted,
+            }),
+            // The rest of lines are written as continuations.
+            explanation: word
+                .explanation
+                .as_ref()
+                .and_then(|text| text.lines().next())
+                .map(|line| ExplanationRef::Text(line.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Document;
+    use crate::model::{parse, Deck, Notation, StressStyle};
+
+    #[test]
+    fn test_roundtrip() {
+        for deck in Deck::builtin() {
+            let document = Document::parse(&deck.source, deck.notation).unwrap();
+            let written = document.to_string();
+            assert_eq!(Document::parse(&written, deck.notation).unwrap(), document);
+            assert_eq!(parse(&written), parse(&deck.source));
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let data = "
+        // Комментарий
+        > ТЕГ: Пояснение.
+        # Слова
+
+
+        шофЁр : ЁР
+        звонИт
+        вероисповЕдание < Т.к. исповЕдать.
+        | Т.к. вероисповЕдник.
+        отзЫв (посла) : ЗЫВ > ТЕГ
+        ";
+        let expected = "\
+// Комментарий
+> ТЕГ: Пояснение.
+
+# Слова
+
+вероисповЕдание       < Т.к. исповЕдать.
+                      | Т.к. вероисповЕдник.
+звонИт
+отзЫв (посла)   : ЗЫВ > ТЕГ
+шофЁр           : ЁР
+";
+        let formatted = Document::parse(data, Notation::Auto).unwrap().format();
+        assert_eq!(formatted, expected);
+        assert_eq!(
+            Document::parse(&formatted, Notation::Auto)
+                .unwrap()
+                .format(),
+            formatted
+        );
+    }
+
+    #[test]
+    fn test_format_keeps_notation() {
+        let data = "
+        мол+око = мол+око
+        Москва\u{301}
+        \"дО смерти\"
+        ";
+        let expected = "\
+\"дО смерти\"
+мол+око = мол+око
+Москва\u{301}
+";
+        let formatted = Document::parse(data, Notation::Auto).unwrap().format();
+        assert_eq!(formatted, expected);
+        let formatted = Document::parse("моло'ко\n", Notation::Apostrophe)
+            .unwrap()
+            .format();
+        assert_eq!(formatted, "моло'ко\n");
+    }
+
+    #[test]
+    fn test_format_keeps_words() {
+        for deck in Deck::builtin() {
+            let formatted = Document::parse(&deck.source, deck.notation)
+                .unwrap()
+                .format();
+            let (mut words, errors, _) = parse(&formatted);
+            let (mut expected, _, _) = parse(&deck.source);
+            assert!(errors.is_empty());
+            words.sort_by_key(|w| w.hash().to_string());
+            expected.sort_by_key(|w| w.hash().to_string());
+            assert_eq!(words, expected);
+        }
+    }
+
+    #[test]
+    fn test_from_words() {
+        for deck in Deck::builtin() {
+            let (words, _, _) = deck.words();
+            for style in StressStyle::ALL {
+                let written = Document::from_words(&words, style).to_string();
+                let (parsed, errors, _) = parse(&written);
+                assert!(errors.is_empty());
+                assert_eq!(parsed.len(), words.len());
+                for (parsed, word) in parsed.iter().zip(&words) {
+                    assert_eq!(parsed.to_string(), word.to_string());
+                    assert_eq!(parsed.group, word.group);
+                    assert_eq!(parsed.explanation, word.explanation);
+                    assert_eq!(parsed.category, word.category);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,385 @@
+//! Importers of word lists kept in spreadsheets or JSON, alongside `data.txt` syntax.
+//!
+//! Tables have header row with columns `word`, `detail`, `lemma`, `form`, `group`, `explanation`
+//! and `category`, which may also be named in Russian: `слово`, `уточнение`, `лемма`, `форма`,
+//! `группа`, `пояснение` and `раздел`.
+//! Only word column is required. JSON is an array of objects with the same fields.
+//! Word is written with uppercase stressed letter and group is prefixed with `!`
+//! if word doesn't follow its rule, like in `data.txt`.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{
+    notation::Notation,
+    parse::{self, parse_with, ParseError, ParseWarning, ParseWarningKind},
+    syntax::{ExplanationRef, GroupRef, LemmaRef, WordLine},
+    word::{Word, WordHash},
+};
+
+/// Format of word list source.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Format {
+    /// `data.txt` syntax.
+    #[default]
+    Text,
+    /// Comma-separated values.
+    Csv,
+    /// Tab-separated values.
+    Tsv,
+    /// Array of objects.
+    Json,
+}
+
+impl Format {
+    /// File types that may be imported, for `accept` attribute of file input.
+    pub const ACCEPT: &'static str = ".txt,.csv,.tsv,.json,text/plain,text/csv,application/json";
+
+    /// Guess format by extension of file name, falling back to `data.txt` syntax.
+    pub fn from_name(name: &str) -> Self {
+        let extension = name.rsplit_once('.').map(|(_, ext)| ext.to_lowercase());
+        match extension.as_deref() {
+            Some("csv") => Format::Csv,
+            Some("tsv" | "tab") => Format::Tsv,
+            Some("json") => Format::Json,
+            _ => Format::Text,
+        }
+    }
+}
+
+/// Parse word list in specified format.
+///
+/// Errors and warnings are located in `source` the same way as for `data.txt` syntax.
+pub fn import(
+    source: &str,
+    format: Format,
+    notation: Notation,
+) -> (Vec<Word>, Vec<ParseError>, Vec<ParseWarning>) {
+    match format {
+        Format::Text => parse_with(source, notation),
+        Format::Csv => table(source, b',', notation),
+        Format::Tsv => table(source, b'\t', notation),
+        Format::Json => json(source, notation),
+    }
+}
+
+/// Word as it is written in table row or JSON object.
+#[derive(Debug, Clone, Deserialize)]
+struct Entry {
+    #[serde(alias = "слово")]
+    word: String,
+    #[serde(default, alias = "уточнение")]
+    detail: Option<String>,
+    #[serde(default, alias = "лемма")]
+    lemma: Option<String>,
+    #[serde(default, alias = "форма")]
+    form: Option<String>,
+    #[serde(default, alias = "группа")]
+    group: Option<String>,
+    #[serde(default, alias = "пояснение")]
+    explanation: Option<String>,
+    #[serde(default, alias = "раздел")]
+    category: Option<String>,
+}
+
+impl Entry {
+    /// Create word of entry with stress in `notation`, failing if it has no stressed letter.
+    fn word(&self, notation: Notation) -> Result<Word, parse::WordParseError> {
+        let field = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(String::from)
+        };
+        let group = field(&self.group).map(|group| {
+            let inverted = group.starts_with('!');
+            let name = group.trim_start_matches(['!', ':']).trim().to_string();
+            GroupRef { name, inverted }
+        });
+        let lemma = match field(&self.lemma) {
+            Some(written) => Some(LemmaRef {
+                word: notation.normalize(&written)?,
+                written,
+                form: field(&self.form),
+            }),
+            None => None,
+        };
+        let syntax = WordLine {
+            word: notation.normalize(self.word.trim())?,
+            written: self.word.trim().to_string(),
+            detail: field(&self.detail),
+            lemma,
+            group: group.filter(|group| !group.name.is_empty()),
+            explanation: field(&self.explanation).map(ExplanationRef::Text),
+        };
+        let mut word = parse::inline_word(&syntax)?;
+        if let Some(category) = field(&self.category) {
+            word = word.with_category(&category);
+        }
+        Ok(word)
+    }
+}
+
+/// Error specific to tables and JSON.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ImportError {
+    #[error("Table has no `word` column.")]
+    NoWordColumn,
+    #[error("Invalid table: {0}")]
+    Table(String),
+    #[error("Invalid JSON: {0}")]
+    Json(String),
+}
+
+/// Words with errors and warnings found so far.
+#[derive(Default)]
+struct Imported {
+    words: Vec<Word>,
+    errors: Vec<ParseError>,
+    warnings: Vec<ParseWarning>,
+    /// Line of the first definition of word.
+    defined: HashMap<WordHash, usize>,
+}
+
+impl Imported {
+    /// Add word of entry found at byte `offset` of `source`.
+    fn push(&mut self, source: &str, offset: usize, entry: &Entry, notation: Notation) {
+        let (line, raw, start) = locate(source, offset);
+        // Point at word itself if it can be found in line.
+        let span = match raw[start..].find(entry.word.trim()) {
+            Some(found) => start + found..start + found + entry.word.trim().len(),
+            None => start..raw.trim_end().len().max(start),
+        };
+        match entry.word(notation) {
+            Ok(word) => {
+                if let Some(&first) = self.defined.get(&word.hash()) {
+                    let kind = ParseWarningKind::DuplicateWord {
+                        word: word.to_string(),
+                        line: first,
+                    };
+                    self.warnings.push(ParseWarning::new(line, raw, span, kind));
+                } else {
+                    self.defined.insert(word.hash(), line);
+                    self.words.push(word);
+                }
+            }
+            Err(e) => self.errors.push(ParseError::new(line, raw, span, e.into())),
+        }
+    }
+
+    /// Add error located at byte `offset` of `source`.
+    fn error(&mut self, source: &str, offset: usize, error: ImportError) {
+        let (line, raw, start) = locate(source, offset);
+        let span = start..raw.trim_end().len().max(start);
+        self.errors
+            .push(ParseError::new(line, raw, span, error.into()));
+    }
+
+    fn finish(self) -> (Vec<Word>, Vec<ParseError>, Vec<ParseWarning>) {
+        (self.words, self.errors, self.warnings)
+    }
+}
+
+/// Import table with header row, which fields are separated by `delimiter`.
+fn table(
+    source: &str,
+    delimiter: u8,
+    notation: Notation,
+) -> (Vec<Word>, Vec<ParseError>, Vec<ParseWarning>) {
+    let mut imported = Imported::default();
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(source.as_bytes());
+
+    let headers = match reader.headers() {
+        Ok(headers) => headers
+            .iter()
+            .map(|header| header.trim_start_matches('\u{feff}').to_lowercase())
+            .collect::<csv::StringRecord>(),
+        Err(e) => {
+            let offset = e.position().map_or(0, |p| p.byte() as usize);
+            imported.error(source, offset, ImportError::Table(e.to_string()));
+            return imported.finish();
+        }
+    };
+    if !headers
+        .iter()
+        .any(|header| header == "word" || header == "слово")
+    {
+        imported.error(source, 0, ImportError::NoWordColumn);
+        return imported.finish();
+    }
+
+    let mut record = csv::StringRecord::new();
+    loop {
+        let offset = reader.position().byte() as usize;
+        match reader.read_record(&mut record) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => {
+                imported.error(source, offset, ImportError::Table(e.to_string()));
+                // Reader may fail to move past malformed data.
+                match reader.position().byte() as usize == offset {
+                    true => break,
+                    false => continue,
+                }
+            }
+        }
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+        match record.deserialize::<Entry>(Some(&headers)) {
+            Ok(entry) => imported.push(source, offset, &entry, notation),
+            Err(e) => imported.error(source, offset, ImportError::Table(e.to_string())),
+        }
+    }
+    imported.finish()
+}
+
+/// Import JSON array of objects.
+fn json(source: &str, notation: Notation) -> (Vec<Word>, Vec<ParseError>, Vec<ParseWarning>) {
+    let mut imported = Imported::default();
+    let entries: Vec<Entry> = match serde_json::from_str(source) {
+        Ok(entries) => entries,
+        Err(e) => {
+            let offset = offset(source, e.line(), e.column().saturating_sub(1));
+            imported.error(source, offset, ImportError::Json(e.to_string()));
+            return imported.finish();
+        }
+    };
+    // JSON parser doesn't keep locations, so words are searched in order of appearance.
+    let mut cursor = 0;
+    for entry in &entries {
+        let literal = serde_json::to_string(&entry.word).unwrap_or_default();
+        if let Some(found) = source[cursor..].find(&literal) {
+            cursor += found;
+        }
+        imported.push(source, cursor, entry, notation);
+    }
+    imported.finish()
+}
+
+/// Get one-based number, text and byte in line of byte `offset` of `source`.
+fn locate(source: &str, offset: usize) -> (usize, &str, usize) {
+    let offset = floor_char_boundary(source, offset.min(source.len()));
+    let start = source[..offset]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1);
+    let end = source[offset..]
+        .find('\n')
+        .map_or(source.len(), |newline| offset + newline);
+    let line = source[..start].matches('\n').count() + 1;
+    let raw = source[start..end].trim_end_matches('\r');
+    (line, raw, (offset - start).min(raw.len()))
+}
+
+/// Get byte offset of one-based `line` and zero-based byte `column` of `source`.
+fn offset(source: &str, line: usize, column: usize) -> usize {
+    let start: usize = source
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    (start + column).min(source.len())
+}
+
+/// Move byte `offset` back until it is at char boundary.
+fn floor_char_boundary(s: &str, mut offset: usize) -> usize {
+    while !s.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Range;
+
+    use super::{import, Format, ImportError, Notation};
+    use crate::model::{
+        parse::{ParseErrorKind, WordParseError},
+        ParseError, Word,
+    };
+
+    /// Get line and columns of error.
+    fn columns(error: &ParseError) -> (usize, Range<usize>) {
+        (error.line, error.columns.clone())
+    }
+
+    #[test]
+    fn test_format_from_name() {
+        assert_eq!(Format::from_name("words.CSV"), Format::Csv);
+        assert_eq!(Format::from_name("words.tsv"), Format::Tsv);
+        assert_eq!(Format::from_name("words.json"), Format::Json);
+        assert_eq!(Format::from_name("words.txt"), Format::Text);
+        assert_eq!(Format::from_name("words"), Format::Text);
+    }
+
+    #[test]
+    fn test_csv() {
+        let data = "\
+Word,Detail,Group,Explanation,Category
+отзЫв,(посла),ЗЫВ,,Существительные
+вероисповЕдание,,,\"Т.к. исповЕдать,\nа не исповедАть.\",
+кремЕнь,,!КРЕМЕНЬ,,
+";
+        let (words, errors, warnings) = import(data, Format::Csv, Notation::Auto);
+        assert!(errors.is_empty(), "{errors:?}");
+        assert!(warnings.is_empty());
+        assert_eq!(
+            words,
+            vec![
+                Word::new("отзыв", 3)
+                    .with_detail("(посла)")
+                    .with_group("ЗЫВ", false)
+                    .with_category("Существительные"),
+                Word::new("вероисповедание", 9)
+                    .with_explanation("Т.к. исповЕдать,\nа не исповедАть."),
+                Word::new("кремень", 4).with_group("КРЕМЕНЬ", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tsv_errors() {
+        let data = "слово\tраздел\nслОво\tГлаголы\n\tкот\nслОво\n";
+        let (words, errors, warnings) = import(data, Format::Tsv, Notation::Auto);
+        assert_eq!(words, vec![Word::new("слово", 2).with_category("Глаголы")]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(columns(&errors[0]), (3, 1..1));
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::Word(WordParseError::EmphasisNotFound(String::new()))
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].line, 4);
+
+        let (_, errors, _) = import("word,group\n", Format::Csv, Notation::Auto);
+        assert!(errors.is_empty());
+        let (_, errors, _) = import("группа\nА\n", Format::Csv, Notation::Auto);
+        assert_eq!(errors[0].kind, ImportError::NoWordColumn.into());
+    }
+
+    #[test]
+    fn test_json() {
+        let data = r#"[
+    {"word": "шофЁр", "group": "ЁР"},
+    {"слово": "звонит"}
+]"#;
+        let (words, errors, _) = import(data, Format::Json, Notation::Auto);
+        assert_eq!(words, vec![Word::new("шофёр", 3).with_group("ЁР", false)]);
+        assert_eq!(columns(&errors[0]), (3, 16..22));
+
+        let (_, errors, _) = import("[{\"word\": 1}]", Format::Json, Notation::Auto);
+        assert_eq!(errors[0].line, 1);
+        assert!(matches!(
+            errors[0].kind,
+            ParseErrorKind::Import(ImportError::Json(_))
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+//! Light markup of explanations.
+//!
+//! Explanation may span several lines, each of them is a paragraph.
+//! `*text*` is emphasized and `[[слОво]]` refers to another word.
+//! Words with uppercase vowel in the middle, like `исповЕдать`, have stressed letter highlighted.
+
+use crate::text;
+
+/// Part of paragraph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Span {
+    Text(String),
+    Emphasis(String),
+    /// Word with stress at specified letter.
+    Stressed(String, usize),
+    /// Reference to another word, as it is written.
+    Link(String),
+}
+
+/// Parse explanation into paragraphs.
+pub fn parse(text: &str) -> Vec<Vec<Span>> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_paragraph)
+        .collect()
+}
+
+/// Get text of explanation without markup.
+pub fn plain(text: &str) -> String {
+    parse(text)
+        .iter()
+        .map(|paragraph| {
+            paragraph
+                .iter()
+                .map(|span| match span {
+                    Span::Text(text) | Span::Emphasis(text) | Span::Link(text) => text.as_str(),
+                    Span::Stressed(word, _) => word.as_str(),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Get all words that explanation refers to.
+pub fn links(text: &str) -> Vec<String> {
+    parse(text)
+        .into_iter()
+        .flatten()
+        .filter_map(|span| match span {
+            Span::Link(word) => Some(word),
+            _ => None,
+        })
+        .collect()
+}
+
+fn parse_paragraph(mut line: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    while !line.is_empty() {
+        let (text, markup, rest) = match next_markup(line) {
+            Some(found) => found,
+            None => (line, None, ""),
+        };
+        push_text(&mut spans, text);
+        spans.extend(markup);
+        line = rest;
+    }
+    spans
+}
+
+/// Find the first complete markup in line.
+///
+/// Returns text before it, markup itself and the rest of line.
+fn next_markup(line: &str) -> Option<(&str, Option<Span>, &str)> {
+    let start = line.find(['*', '['])?;
+    let (before, from) = line.split_at(start);
+    let found = if let Some(inner) = from.strip_prefix("[[") {
+        inner
+            .split_once("]]")
+            .map(|(word, rest)| (Span::Link(word.trim().to_string()), rest))
+    } else if let Some(inner) = from.strip_prefix('*') {
+        inner
+            .split_once('*')
+            .filter(|(text, _)| !text.is_empty())
+            .map(|(text, rest)| (Span::Emphasis(text.to_string()), rest))
+    } else {
+        None
+    };
+    match found {
+        Some((span, rest)) => Some((before, Some(span), rest)),
+        // Markup isn't closed, so its opening char is plain text.
+        None => {
+            let (opening, rest) = from.split_at(1);
+            Some((&line[..before.len() + opening.len()], None, rest))
+        }
+    }
+}
+
+/// Push plain text, separating words with stressed letter.
+fn push_text(spans: &mut Vec<Span>, text: &str) {
+    let mut plain = String::new();
+    let mut word = String::new();
+    for c in text.chars() {
+        // Combining marks, like acute accent, belong to word too.
+        if c.is_alphabetic() || c == '\u{301}' {
+            word.push(c);
+        } else {
+            push_word(spans, &mut plain, &mut word);
+            plain.push(c);
+        }
+    }
+    push_word(spans, &mut plain, &mut word);
+    if !plain.is_empty() {
+        match spans.last_mut() {
+            Some(Span::Text(last)) => last.push_str(&plain),
+            _ => spans.push(Span::Text(plain)),
+        }
+    }
+}
+
+/// Move `word` either to `plain` text or, if it has stressed letter, to `spans`.
+fn push_word(spans: &mut Vec<Span>, plain: &mut String, word: &mut String) {
+    match stress(word) {
+        Some(pos) => {
+            if !plain.is_empty() {
+                spans.push(Span::Text(std::mem::take(plain)));
+            }
+            spans.push(Span::Stressed(std::mem::take(word), pos));
+        }
+        None => plain.push_str(&std::mem::take(word)),
+    }
+}
+
+/// Get position of stressed letter of word written with uppercase vowel in the middle.
+fn stress(word: &str) -> Option<usize> {
+    let letters = text::letters(word);
+    let mut uppercase = letters
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| text::is_uppercase(l));
+    let (pos, letter) = uppercase.next()?;
+    match uppercase.next() {
+        None if pos > 0 && text::is_vowel(letter) => Some(pos),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{links, parse, plain, Span};
+
+    #[test]
+    fn test_markup() {
+        let text =
+            "Т.к. *исповЕдать*.\n  Но [[Отзыв (о книге)]] — это другое, см. вероисповЕдание ";
+        let expected = vec![
+            vec![
+                Span::Text(String::from("Т.к. ")),
+                Span::Emphasis(String::from("исповЕдать")),
+                Span::Text(String::from(".")),
+            ],
+            vec![
+                Span::Text(String::from("Но ")),
+                Span::Link(String::from("Отзыв (о книге)")),
+                Span::Text(String::from(" — это другое, см. ")),
+                Span::Stressed(String::from("вероисповЕдание"), 9),
+            ],
+        ];
+        assert_eq!(parse(text), expected);
+        assert_eq!(links(text), vec![String::from("Отзыв (о книге)")]);
+        assert_eq!(
+            plain(text),
+            "Т.к. исповЕдать. Но Отзыв (о книге) — это другое, см. вероисповЕдание"
+        );
+    }
+
+    #[test]
+    fn test_unclosed_markup() {
+        let expected = vec![vec![Span::Text(String::from("5 * 3 [[ ЕГЭ"))]];
+        assert_eq!(parse("5 * 3 [[ ЕГЭ"), expected);
+    }
+}
@@ -1,92 +1,306 @@
+mod custom;
+mod deck;
+mod format;
+mod import;
+pub mod markup;
+mod notation;
 mod parse;
+mod settings;
+pub mod share;
 mod statistics;
+mod stress;
+mod syllable;
+mod syntax;
 mod variant;
 mod word;
 
-use indexmap::IndexMap;
+use std::collections::HashMap;
 
-pub use self::parse::ParseError;
+use indexmap::{map::Entry, IndexMap};
+
+use self::parse::ParseWarningKind;
+pub use self::parse::{parse, ParseError, ParseWarning};
 use self::statistics::Stats;
 pub use self::{
+    custom::CustomDeck,
+    deck::{Deck, ProgressScope},
+    format::Document,
+    import::{import, Format},
+    notation::Notation,
+    settings::{CardOrder, Settings, Theme, VariantLayout},
+    statistics::{Group, Snapshot, WordState},
+    stress::{StressStyle, Stressed},
     variant::Variant,
-    word::{Word, WordHash},
+    word::{Word, WordGroup, WordHash},
 };
 
+/// Errors or warnings along with id of deck they were found in.
+pub type Diagnostics<T> = Vec<(String, T)>;
+
 /// Struct that manages whole logic of trainer.
 pub struct Model {
     pub stats: Stats,
     latest: Option<WordHash>,
     words: IndexMap<WordHash, Word>,
+    /// Hashes of words by hashes they had before detail became a part of word's identity.
+    renamed: HashMap<WordHash, WordHash>,
 }
 
 impl Model {
-    /// Create new model.
-    pub fn new() -> (Self, Vec<ParseError>) {
-        let data = include_str!("./data.txt");
-        let (words, errors) = parse::parse(data);
-
-        let stats = Stats::new(words.iter().map(|w| w.hash()).collect());
-        let words = words
-            .into_iter()
-            .map(|word| (WordHash::from(&word), word))
-            .collect();
-
+    /// Create new model with words from provided decks.
+    ///
+    /// Words with the same hash are merged, the first definition is kept.
+    /// Errors and warnings are returned along with id of deck they were found in.
+    pub fn new(decks: &[Deck]) -> (Self, Diagnostics<ParseError>, Diagnostics<ParseWarning>) {
+        let (words, errors, warnings) = Self::load(decks);
+        let renamed = Self::renamed(&words);
+        let stats = Stats::new(words.keys().copied().collect(), &renamed);
         let model = Model {
             stats,
             latest: None,
             words,
+            renamed,
         };
-        (model, errors)
+        (model, errors, warnings)
     }
 
-    /// Get new word.
-    pub fn next(&mut self) -> Word {
-        if self.words.len() < 2 {
-            let key = self.stats.next();
-            self.words.get(&key).unwrap().clone()
-        } else {
-            loop {
-                let key = self.stats.next();
-                if Some(key) != self.latest {
-                    break self.words.get(&key).unwrap().clone();
+    /// Get hashes of words with detail by hashes they had before detail became a part of hash.
+    ///
+    /// Old hash is kept only if it is claimed by exactly one word and isn't a hash of another one.
+    fn renamed(words: &IndexMap<WordHash, Word>) -> HashMap<WordHash, WordHash> {
+        let mut claims: HashMap<WordHash, Vec<WordHash>> = HashMap::new();
+        for word in words.values() {
+            if let Some(legacy) = word.legacy_hash() {
+                claims.entry(legacy).or_default().push(word.hash());
+            }
+        }
+        claims
+            .into_iter()
+            .filter(|(legacy, claims)| claims.len() == 1 && !words.contains_key(legacy))
+            .map(|(legacy, claims)| (legacy, claims[0]))
+            .collect()
+    }
+
+    /// Parse words of decks.
+    fn load(
+        decks: &[Deck],
+    ) -> (
+        IndexMap<WordHash, Word>,
+        Diagnostics<ParseError>,
+        Diagnostics<ParseWarning>,
+    ) {
+        let mut words = IndexMap::new();
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        // Deck that each word was taken from.
+        let mut origins: HashMap<WordHash, &str> = HashMap::new();
+        for deck in decks {
+            let (deck_words, deck_errors, deck_warnings) = deck.words();
+            errors.extend(deck_errors.into_iter().map(|e| (deck.id.clone(), e)));
+            warnings.extend(deck_warnings.into_iter().map(|w| (deck.id.clone(), w)));
+            for word in deck_words {
+                match words.entry(word.hash()) {
+                    Entry::Vacant(entry) => {
+                        origins.insert(word.hash(), &deck.id);
+                        entry.insert(word);
+                    }
+                    // Duplicates within one deck are already reported by parser.
+                    Entry::Occupied(_) if origins[&word.hash()] == deck.id => {}
+                    Entry::Occupied(_) => {
+                        let kind = ParseWarningKind::DefinedInDeck {
+                            word: word.to_string(),
+                            deck: origins[&word.hash()].to_string(),
+                        };
+                        warnings.push((deck.id.clone(), ParseWarning::unlocated(kind)));
+                    }
                 }
             }
         }
+        (words, errors, warnings)
+    }
+
+    /// Get new word.
+    ///
+    /// Latest word and other forms of its lemma are shown only if there is nothing else.
+    /// Returns `None` if all words are suspended.
+    pub fn next(&mut self, order: CardOrder) -> Option<Word> {
+        let words = &self.words;
+        let latest = self.latest.and_then(|latest| words.get(&latest));
+        let key = self
+            .stats
+            .next(order, |key| match (latest, words.get(&key)) {
+                (Some(latest), Some(word)) => word.lemma() == latest.lemma(),
+                _ => false,
+            })?;
+        self.latest = Some(key);
+        self.words.get(&key).cloned()
+    }
+
+    /// Get all words.
+    pub fn words(&self) -> impl Iterator<Item = &Word> {
+        self.words.values()
+    }
+
+    /// Get word by its hash.
+    ///
+    /// Hash that word had in older versions is accepted as well, so old links keep working.
+    pub fn word(&self, hash: WordHash) -> Option<&Word> {
+        let hash = self.renamed.get(&hash).copied().unwrap_or(hash);
+        self.words.get(&hash)
+    }
+
+    /// Get learning progress of all words.
+    pub fn progress(&self) -> Progress {
+        let mut progress = Progress {
+            levels: [0; Group::COUNT],
+            due: 0,
+        };
+        for &hash in self.words.keys() {
+            let level = self.stats.group(hash).unwrap_or_default().level();
+            progress.levels[level as usize] += 1;
+            if self.stats.is_due(hash) {
+                progress.due += 1;
+            }
+        }
+        progress
+    }
+
+    /// Get report on words that user marked as ones with possibly incorrect data.
+    ///
+    /// Stressed letters are written in `style`.
+    pub fn flagged_report(&self, style: StressStyle) -> String {
+        let mut report = String::from("// Слова, отмеченные как содержащие ошибку\n");
+        for word in self
+            .words
+            .values()
+            .filter(|w| self.stats.state(w.hash()).flagged)
+        {
+            report.push_str(&word.stressed(style).to_string());
+            if let Some(category) = &word.category {
+                report.push_str(&format!(" // {category}"));
+            }
+            report.push('\n');
+        }
+        report
+    }
+
+    /// Get learning progress of words of each group, in order of appearance.
+    pub fn group_progress(&self) -> Vec<GroupProgress> {
+        let mut groups: IndexMap<String, GroupProgress> = IndexMap::new();
+        for word in self.words.values() {
+            let group = match &word.group {
+                Some(group) => group,
+                None => continue,
+            };
+            let progress = groups.entry(group.id()).or_insert_with(|| GroupProgress {
+                name: group.name.clone(),
+                words: 0,
+                levels: 0,
+            });
+            progress.words += 1;
+            progress.levels += self.stats.group(word.hash()).unwrap_or_default().level() as u32;
+        }
+        groups.into_values().collect()
     }
 
     /// Get words with the same group and rule.
     pub fn seealso(&self, word: &Word) -> Vec<Word> {
-        let group = match word.group {
-            Some(group) => group,
-            None => return Vec::new(),
-        };
-        self.words
+        self.related(word, |group, other| {
+            group.same(other) && group.inverted == other.inverted
+        })
+    }
+
+    /// Get word with the same group but opposite rule.
+    pub fn opposite(&self, word: &Word) -> Vec<Word> {
+        self.related(word, |group, other| {
+            group.same(other) && group.inverted != other.inverted
+        })
+    }
+
+    /// Get all forms of lemma of `word` including the word itself, in order of definition.
+    ///
+    /// Returns nothing if none of them is defined as a form of lemma.
+    pub fn paradigm(&self, word: &Word) -> Vec<Word> {
+        let forms = self
+            .words
             .values()
-            .filter(|w| w.group.map(|g| g == group).unwrap_or(false))
+            .filter(|w| w.lemma() == word.lemma())
             .cloned()
-            .filter(|w| w != word)
+            .collect::<Vec<_>>();
+        match forms.iter().any(|w| w.form.is_some()) {
+            true => forms,
+            false => Vec::new(),
+        }
+    }
+
+    /// Get words that explanation of `word` refers to, along with text of reference.
+    ///
+    /// Reference matches word written in any case, with or without detail.
+    pub fn links(&self, word: &Word) -> Vec<(String, WordHash)> {
+        let explanation = match &word.explanation {
+            Some(explanation) => explanation,
+            None => return Vec::new(),
+        };
+        markup::links(explanation)
+            .into_iter()
+            .filter_map(|link| {
+                let target = link.to_lowercase();
+                let found = self.words.values().find(|w| w.key() == target);
+                let found = found.or_else(|| self.words.values().find(|w| w.inner() == target));
+                found.map(|w| (link, w.hash()))
+            })
             .collect()
     }
 
-    /// Get word with the same group but opposite rule.
-    pub fn opposite(&self, word: &Word) -> Vec<Word> {
-        let group = match word.group {
+    /// Get other words which group matches group of `word` by `f`.
+    fn related(&self, word: &Word, f: impl Fn(&WordGroup, &WordGroup) -> bool) -> Vec<Word> {
+        let group = match &word.group {
             Some(group) => group,
             None => return Vec::new(),
         };
         self.words
             .values()
-            .filter(|w| {
-                w.group
-                    .map(|g| g.0 == !group.0 && g.1 == group.1)
-                    .unwrap_or(false)
-            })
+            .filter(|w| w.group.as_ref().map(|g| f(group, g)).unwrap_or(false))
+            .filter(|w| *w != word)
             .cloned()
-            .filter(|w| w != word)
             .collect()
     }
 }
 
+/// Learning progress of words of one group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupProgress {
+    /// Name of group.
+    pub name: String,
+    /// Number of words in group.
+    pub words: u32,
+    /// Sum of levels of all words in group.
+    pub levels: u32,
+}
+
+impl GroupProgress {
+    /// Get sum of levels that words have when they all are learnt.
+    pub fn max_levels(&self) -> u32 {
+        self.words * (Group::COUNT as u32 - 1)
+    }
+}
+
+/// Number of words at each level of learning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    /// Number of words in each group.
+    pub levels: [u32; Group::COUNT],
+    /// Number of words that should be repeated.
+    pub due: u32,
+}
+
+impl Progress {
+    /// Total number of words.
+    pub fn total(&self) -> u32 {
+        self.levels.iter().sum()
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum CardResult {
     Solved,
@@ -95,21 +309,112 @@ pub enum CardResult {
 
 #[cfg(test)]
 mod test {
-    use super::Model;
+    use std::collections::{HashMap, HashSet};
+
+    use indexmap::IndexMap;
+
+    use super::{CardOrder, Deck, Model, ParseWarningKind, Stats, Word};
 
     #[test]
     fn test_all_data_loaded_correctly() {
-        let (_, errors) = Model::new();
-        assert!(errors.is_empty());
+        let (_, errors, warnings) = Model::load(&Deck::builtin());
+        let rendered = errors
+            .iter()
+            .map(|(deck, e)| e.render(deck))
+            .chain(warnings.iter().map(|(deck, w)| w.render(deck)))
+            .collect::<Vec<_>>();
+        assert!(rendered.is_empty(), "\n{}", rendered.join("\n\n"));
+    }
+
+    #[test]
+    fn test_paradigm() {
+        let (words, _, _) = Model::load(&Deck::builtin());
+        let model = Model {
+            stats: Stats::default(),
+            latest: None,
+            words,
+            renamed: HashMap::new(),
+        };
+        let word = model.words().find(|w| w.inner() == "ногтя").unwrap();
+        let paradigm = model.paradigm(word);
+        let forms = paradigm.iter().map(Word::to_string).collect::<Vec<_>>();
+        assert_eq!(forms, ["нОготь", "нОгтя"]);
+        assert_eq!(model.paradigm(&paradigm[0]), paradigm);
+
+        let word = model.words().find(|w| w.inner() == "дозвониться").unwrap();
+        assert!(model.paradigm(word).is_empty());
+    }
+
+    /// Test that word defined in several decks is reported.
+    #[test]
+    fn test_duplicates_across_decks() {
+        let mut extra = Deck::builtin().remove(0);
+        extra.id = String::from("extra");
+        extra.source = String::from("аэропОрты\nнаверхУ");
+        extra.preparsed = None;
+        let mut decks = Deck::builtin();
+        decks.push(extra);
+        let (_, _, warnings) = Model::load(&decks);
+        let kinds = warnings
+            .into_iter()
+            .map(|(_, w)| w.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [ParseWarningKind::DefinedInDeck {
+                word: String::from("аэропОрты"),
+                deck: String::from("ege"),
+            }]
+        );
+    }
+
+    /// Test that old hash of word with detail is mapped to the new one only if it's unambiguous.
+    #[test]
+    fn test_renamed() {
+        let words = [
+            Word::new("замок", 1).with_detail("(дворец)"),
+            Word::new("мука", 1).with_detail("(страдание)"),
+            Word::new("мука", 3).with_detail("(продукт)"),
+        ];
+        let legacy = words
+            .iter()
+            .map(|w| w.legacy_hash().unwrap())
+            .collect::<Vec<_>>();
+        let words = words.into_iter().map(|w| (w.hash(), w)).collect();
+        let renamed = Model::renamed(&words);
+        assert_eq!(renamed.len(), 1);
+        assert_eq!(renamed[&legacy[0]], words[0].hash());
+    }
+
+    /// Test that forms of the same lemma defined next to each other aren't skipped.
+    #[test]
+    fn test_sequential_order_reaches_all_words() {
+        let (words, _, _) = Model::load(&Deck::builtin());
+        let stats = Stats::with_records(
+            IndexMap::new(),
+            words.keys().copied().collect(),
+            &HashMap::new(),
+        );
+        let mut model = Model {
+            stats,
+            latest: None,
+            words,
+            renamed: HashMap::new(),
+        };
+        let mut shown = HashSet::new();
+        for _ in 0..model.words.len() * 2 {
+            shown.insert(model.next(CardOrder::Sequential).unwrap().hash());
+        }
+        assert_eq!(shown.len(), model.words.len());
     }
 
     /// Test that word isn't shown twice in a row.
     #[test]
     fn test_words_dont_repeat() {
-        let (mut model, _) = Model::new();
+        let (mut model, _, _) = Model::new(&Deck::builtin());
         let mut last = None;
         for _ in 0..5000 {
-            let word = model.next();
+            let word = model.next(CardOrder::Random).unwrap();
             assert_ne!(Some(word.clone()), last);
             last = Some(word);
         }
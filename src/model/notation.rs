@@ -0,0 +1,287 @@
+//! Notations of stressed letter used in word lists.
+//!
+//! Internally stressed letter is written uppercase, so other notations are normalized to it.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::text;
+
+use super::parse::WordParseError;
+
+/// Combining acute accent placed after stressed letter.
+const ACUTE: char = '\u{301}';
+/// Combining grave accent placed after letter with secondary stress.
+pub(super) const GRAVE: char = '\u{300}';
+
+/// Way to mark stressed letter of word.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Notation {
+    /// Any notation below, but only one per word.
+    /// Uppercase first letter is treated as capitalization if word has other mark.
+    #[default]
+    Auto,
+    /// Stressed letter is uppercase: `молОко`.
+    Uppercase,
+    /// Combining acute accent after stressed vowel: `моло́ко`.
+    Acute,
+    /// Apostrophe after stressed vowel: `моло'ко`.
+    Apostrophe,
+    /// Plus before stressed vowel: `мол+око`.
+    Plus,
+}
+
+impl Notation {
+    pub const ALL: [Notation; 5] = [
+        Notation::Auto,
+        Notation::Uppercase,
+        Notation::Acute,
+        Notation::Apostrophe,
+        Notation::Plus,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Notation::Auto => "Любое",
+            Notation::Uppercase => "Заглавная буква: молОко",
+            Notation::Acute => "Знак ударения: моло́ко",
+            Notation::Apostrophe => "Апостроф: моло'ко",
+            Notation::Plus => "Плюс: мол+око",
+        }
+    }
+
+    /// Rewrite word with stressed letter marked by this notation to uppercase notation.
+    ///
+    /// Word with no stress, several stresses or marks of another notation is rejected.
+    /// Secondary stress is kept as combining grave accent in any notation,
+    /// backtick after letter is accepted for it as well.
+    pub fn normalize(self, word: &str) -> Result<String, WordParseError> {
+        let word = &word.replace('`', &GRAVE.to_string());
+        // Uppercase vowel that doesn't start word can't be told from stress, so it is a mark
+        // unless notation is set explicitly.
+        let marks = marks(word, self == Notation::Auto)?;
+        let mut found = marks.iter().map(|(notation, _)| *notation);
+        let used = match (self, found.next()) {
+            (Notation::Auto, None) => Notation::Uppercase,
+            (Notation::Auto, Some(first)) => match found.find(|n| *n != first) {
+                Some(second) => {
+                    return Err(WordParseError::AmbiguousStress {
+                        word: word.to_string(),
+                        first,
+                        second,
+                    })
+                }
+                None => first,
+            },
+            (expected, Some(found)) if found != expected => {
+                return Err(WordParseError::UnexpectedNotation {
+                    word: word.to_string(),
+                    expected,
+                    found,
+                })
+            }
+            (expected, _) => expected,
+        };
+
+        if used == Notation::Uppercase {
+            // Uppercase notation is kept as it is written.
+            return match text::uppercase_position(word) {
+                Some(_) => Ok(word.to_string()),
+                None => Err(WordParseError::EmphasisNotFound(word.to_string())),
+            };
+        }
+        match marks.as_slice() {
+            [] => Err(WordParseError::EmphasisNotFound(word.to_string())),
+            [(_, position)] => {
+                let plain = word.chars().filter(|c| !is_mark(*c)).collect::<String>();
+                Ok(text::uppercase_letter(&text::lowercase(&plain), *position))
+            }
+            _ => Err(WordParseError::MultipleStress(word.to_string())),
+        }
+    }
+}
+
+impl Display for Notation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Notation::Auto => "any notation",
+            Notation::Uppercase => "uppercase letter",
+            Notation::Acute => "combining acute accent",
+            Notation::Apostrophe => "apostrophe after vowel",
+            Notation::Plus => "`+` before vowel",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Remove secondary stress marks from normalized word.
+///
+/// Returns word without marks and positions of letters with secondary stress.
+pub(super) fn secondary(word: &str) -> Result<(String, Vec<usize>), WordParseError> {
+    let mut positions = Vec::new();
+    for (pos, letter) in text::letters(word).into_iter().enumerate() {
+        if !letter.contains(GRAVE) {
+            continue;
+        }
+        if !text::is_vowel(letter) || text::is_uppercase(letter) {
+            return Err(WordParseError::MisplacedSecondaryStress(word.to_string()));
+        }
+        positions.push(pos);
+    }
+    let plain = word.chars().filter(|c| *c != GRAVE).collect();
+    Ok((plain, positions))
+}
+
+/// Returns true if char marks stress in any notation but uppercase.
+fn is_mark(c: char) -> bool {
+    matches!(c, ACUTE | '\'' | '’' | '+')
+}
+
+/// Find marks of stress, including uppercase vowels other than the first letter if `uppercase`.
+///
+/// Returns notation of each mark with position of letter it marks in word without marks.
+fn marks(word: &str, uppercase: bool) -> Result<Vec<(Notation, usize)>, WordParseError> {
+    let letters = text::letters(word);
+    let mut marks = Vec::new();
+    // Number of letters before current one, that is its position in word without marks.
+    let mut position: usize = 0;
+    for (i, &letter) in letters.iter().enumerate() {
+        // Apostrophe refers to the previous letter, plus to the next one,
+        // and acute accent is a part of letter it refers to.
+        let (notation, marked, target) = match letter {
+            "'" | "’" => {
+                let previous = i.checked_sub(1).map(|i| letters[i]);
+                (Notation::Apostrophe, previous, position.saturating_sub(1))
+            }
+            "+" => (Notation::Plus, letters.get(i + 1).copied(), position),
+            _ if letter.contains(ACUTE) => {
+                position += 1;
+                (Notation::Acute, Some(letter), position - 1)
+            }
+            _ if uppercase && position > 0 && text::is_vowel(letter) => {
+                position += 1;
+                if text::is_uppercase(letter) {
+                    marks.push((Notation::Uppercase, position - 1));
+                }
+                continue;
+            }
+            _ => {
+                position += 1;
+                continue;
+            }
+        };
+        if !marked.is_some_and(text::is_vowel) {
+            return Err(WordParseError::MisplacedStressMark {
+                word: word.to_string(),
+                notation,
+            });
+        }
+        marks.push((notation, target));
+    }
+    Ok(marks)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{secondary, Notation};
+    use crate::model::parse::WordParseError;
+
+    #[test]
+    fn test_normalize() {
+        for word in ["молОко", "моло\u{301}ко", "моло'ко", "моло’ко", "мол+око"]
+        {
+            assert_eq!(Notation::Auto.normalize(word), Ok(String::from("молОко")));
+        }
+        assert_eq!(
+            Notation::Acute.normalize("Москва\u{301}"),
+            Ok(String::from("москвА"))
+        );
+        assert_eq!(Notation::Plus.normalize("+Иксы"), Ok(String::from("Иксы")));
+        assert_eq!(
+            Notation::Uppercase.normalize("Иксы"),
+            Ok(String::from("Иксы"))
+        );
+    }
+
+    #[test]
+    fn test_secondary() {
+        for word in ["га\u{300}зопровОд", "га`зопрово\u{301}д", "га`зопров+од"]
+        {
+            let normalized = Notation::Auto.normalize(word).unwrap();
+            assert_eq!(
+                secondary(&normalized),
+                Ok((String::from("газопровОд"), vec![1]))
+            );
+        }
+        assert_eq!(
+            secondary("г\u{300}азопровОд"),
+            Err(WordParseError::MisplacedSecondaryStress(String::from(
+                "г\u{300}азопровОд"
+            )))
+        );
+        assert_eq!(
+            secondary("газопровО\u{300}д"),
+            Err(WordParseError::MisplacedSecondaryStress(String::from(
+                "газопровО\u{300}д"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_normalize_errors() {
+        assert_eq!(
+            Notation::Auto.normalize("мол+око'"),
+            Err(WordParseError::AmbiguousStress {
+                word: String::from("мол+око'"),
+                first: Notation::Plus,
+                second: Notation::Apostrophe,
+            })
+        );
+        assert_eq!(
+            Notation::Auto.normalize("мОлоко\u{301}"),
+            Err(WordParseError::AmbiguousStress {
+                word: String::from("мОлоко\u{301}"),
+                first: Notation::Uppercase,
+                second: Notation::Acute,
+            })
+        );
+        assert_eq!(
+            Notation::Auto.normalize("мОл+око"),
+            Err(WordParseError::AmbiguousStress {
+                word: String::from("мОл+око"),
+                first: Notation::Uppercase,
+                second: Notation::Plus,
+            })
+        );
+        assert_eq!(
+            Notation::Uppercase.normalize("моло'ко"),
+            Err(WordParseError::UnexpectedNotation {
+                word: String::from("моло'ко"),
+                expected: Notation::Uppercase,
+                found: Notation::Apostrophe,
+            })
+        );
+        assert_eq!(
+            Notation::Plus.normalize("м+локо"),
+            Err(WordParseError::MisplacedStressMark {
+                word: String::from("м+локо"),
+                notation: Notation::Plus,
+            })
+        );
+        assert_eq!(
+            Notation::Auto.normalize("м+ол+око"),
+            Err(WordParseError::MultipleStress(String::from("м+ол+око")))
+        );
+        assert_eq!(
+            Notation::Acute.normalize("мо\u{301}локо\u{301}"),
+            Err(WordParseError::MultipleStress(String::from(
+                "мо\u{301}локо\u{301}"
+            )))
+        );
+        assert_eq!(
+            Notation::Acute.normalize("молоко"),
+            Err(WordParseError::EmphasisNotFound(String::from("молоко")))
+        );
+    }
+}
@@ -1,154 +1,430 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, ops::Range};
 
+use indexmap::IndexMap;
 use thiserror::Error;
 
-use crate::util;
+use crate::text;
 
-use super::Word;
+use super::{
+    import::ImportError,
+    notation::{self, Notation},
+    syntax::{ExplanationRef, LemmaRef, Line, WordLine},
+    word::WordForm,
+    Word,
+};
 
-pub fn parse(s: &str) -> (Vec<Word>, Vec<ParseError>) {
-    let mut words = Vec::with_capacity(s.lines().count());
-    let mut explanations = HashMap::new();
+/// Parse word list with stress in any notation.
+pub fn parse(s: &str) -> (Vec<Word>, Vec<ParseError>, Vec<ParseWarning>) {
+    parse_with(s, Notation::Auto)
+}
+
+/// Parse word list with stress in specified notation.
+pub fn parse_with(s: &str, notation: Notation) -> (Vec<Word>, Vec<ParseError>, Vec<ParseWarning>) {
+    let mut words: Vec<Word> = Vec::with_capacity(s.lines().count());
+    let mut explanations: HashMap<String, Definition> = HashMap::new();
+    let mut category = None;
     let mut errors = Vec::new();
-    for (line, text) in s.lines().enumerate() {
-        if let Some(res) = parse_line(line, text, &explanations) {
-            match res {
-                ParseResult::Word(w) => words.push(w),
-                ParseResult::Explanation(tag, text) => {
-                    explanations.insert(tag, text);
+    let mut warnings = Vec::new();
+    // Location of the first definition of word.
+    let mut defined = HashMap::new();
+    // Definition and members of each group, in order of appearance.
+    let mut groups: IndexMap<String, GroupMembers> = IndexMap::new();
+    // Explanation that continuation line extends.
+    let mut continued = Continued::Nothing;
+    for (index, raw) in s.lines().enumerate() {
+        let line = index + 1;
+        let syntax = match Line::parse(raw, notation) {
+            Ok(syntax) => syntax,
+            Err((kind, span)) => {
+                errors.push(ParseError::new(line, raw, span, kind));
+                continued = Continued::Invalid;
+                continue;
+            }
+        };
+        if let Line::Continuation(text) = syntax {
+            let explanation = match &continued {
+                Continued::Definition(tag) => explanations.get_mut(tag).map(|d| &mut d.text),
+                Continued::Word => words.last_mut().and_then(|w| w.explanation.as_mut()),
+                Continued::Invalid => continue,
+                Continued::Nothing => None,
+            };
+            match explanation {
+                Some(explanation) => {
+                    explanation.push('\n');
+                    explanation.push_str(&text);
+                }
+                None => {
+                    let kind = ExplanationParseError::NothingToContinue.into();
+                    errors.push(ParseError::new(line, raw, trimmed_span(raw), kind));
+                }
+            }
+            continue;
+        }
+        continued = Continued::Nothing;
+        match syntax {
+            Line::Blank | Line::Comment(_) | Line::Continuation(_) => {}
+            Line::Section(name) => category = Some(name),
+            Line::Explanation { tag, text } => {
+                continued = Continued::Definition(tag.to_lowercase());
+                let definition = Definition {
+                    text,
+                    line,
+                    raw: raw.to_string(),
+                    used: false,
+                };
+                explanations.insert(tag.to_lowercase(), definition);
+            }
+            Line::Word(syntax) => {
+                let mut w = match word(&syntax, &mut explanations) {
+                    Ok(w) => w,
+                    Err(e) => {
+                        errors.push(ParseError::new(line, raw, tag_span(raw), e.into()));
+                        continued = Continued::Invalid;
+                        continue;
+                    }
+                };
+                w.category = category.clone();
+                if let Some(&first) = defined.get(&w.hash()) {
+                    let kind = ParseWarningKind::DuplicateWord {
+                        word: w.to_string(),
+                        line: first,
+                    };
+                    warnings.push(ParseWarning::new(line, raw, word_span(raw), kind));
+                    continued = Continued::Invalid;
+                    continue;
                 }
-                ParseResult::Error(err) => errors.push(err),
+                defined.insert(w.hash(), line);
+                if let Some(group) = &syntax.group {
+                    let members =
+                        groups
+                            .entry(group.name.to_lowercase())
+                            .or_insert_with(|| GroupMembers {
+                                name: group.name.clone(),
+                                line,
+                                raw: raw.to_string(),
+                                positive: 0,
+                                inverted: 0,
+                            });
+                    match group.inverted {
+                        true => members.inverted += 1,
+                        false => members.positive += 1,
+                    }
+                }
+                if let Some(ExplanationRef::Text(_)) = syntax.explanation {
+                    continued = Continued::Word;
+                }
+                words.push(w);
             }
         }
     }
-    (words, errors)
-}
 
-fn parse_line(
-    line: usize,
-    text: &str,
-    explanations: &HashMap<String, String>,
-) -> Option<ParseResult> {
-    let text = text.trim();
-    if !should_parse(text) {
-        return None;
-    }
-    let res = match text.strip_prefix('>') {
-        Some(text) => Explanation::from_str(text.trim_start())
-            .map_err(|source| ParseError::new_explanation(line, source))
-            .into(),
-        None => parse_word(text, explanations)
-            .map_err(|source| ParseError::new_word(line, source))
-            .into(),
-    };
-    Some(res)
+    let mut unused = explanations
+        .into_iter()
+        .filter(|(_, definition)| !definition.used)
+        .collect::<Vec<_>>();
+    unused.sort_by_key(|(_, definition)| definition.line);
+    for (tag, definition) in unused {
+        let span = trimmed_span(&definition.raw);
+        let kind = ParseWarningKind::UnusedExplanation(tag);
+        warnings.push(ParseWarning::new(
+            definition.line,
+            &definition.raw,
+            span,
+            kind,
+        ));
+    }
+    for members in groups.into_values() {
+        let kind = if members.positive + members.inverted == 1 {
+            ParseWarningKind::SingleMemberGroup(members.name)
+        } else if members.positive == 0 {
+            ParseWarningKind::OnlyInvertedGroup(members.name)
+        } else {
+            continue;
+        };
+        let span = trimmed_span(&members.raw);
+        warnings.push(ParseWarning::new(members.line, &members.raw, span, kind));
+    }
+    warnings.sort_by_key(|warning| warning.line);
+
+    (words, errors, warnings)
 }
 
-fn should_parse(s: &str) -> bool {
-    !s.is_empty() && !s.starts_with("//")
+/// Create form of word from reference to its lemma.
+fn form(lemma: &LemmaRef) -> Result<WordForm, WordParseError> {
+    let (plain, _) = notation::secondary(&lemma.word)?;
+    let emphasis = text::uppercase_position(&plain)
+        .ok_or_else(|| WordParseError::EmphasisNotFound(lemma.word.clone()))?;
+    Ok(WordForm {
+        lemma: text::lowercase(&plain),
+        emphasis,
+        name: lemma.form.clone(),
+    })
 }
 
-fn parse_word(line: &str, explanations: &HashMap<String, String>) -> Result<Word, WordParseError> {
-    let (word, left) = line
-        .split_once(|c: char| c.is_whitespace())
-        .map(|(word, left)| (word.trim(), Some(left.trim())))
-        .unwrap_or_else(|| (line, None));
-    let emphasis = util::first_uppercase_position(word)
-        .ok_or(WordParseError::EmphasisNotFound(word.to_string()))?;
-    let mut word = Word::new(word, emphasis);
+/// Create word from definition that has no explanation tags, like one imported from table.
+pub(super) fn inline_word(syntax: &WordLine) -> Result<Word, WordParseError> {
+    word(syntax, &mut HashMap::new())
+}
 
-    if let Some(left) = left {
-        // Detail
-        if let Some(detail) = util::subslice_tags(left, &[], &[':', '!', '>', '<']) {
-            word = word.with_detail(&detail);
-        }
-        // Group
-        if left.chars().filter(|c| [':', '!'].contains(c)).count() > 1 {
-            return Err(WordParseError::MoreThanOneGroup(word.inner().to_owned()));
-        }
-        if let Some(group) = util::subslice_tags(left, &[':', '!'], &['>', '<']) {
-            let inverted = left.contains('!');
-            word = word.with_group(group.trim(), inverted)
-        }
-        // Explanation
-        match util::subslice_tags(left, &['>'], &[]) {
-            Some(exp_tag) => {
-                let exp_tag = exp_tag.trim().to_lowercase();
-                if let Some(exp) = explanations.get(&exp_tag) {
-                    word = word.with_explanation(exp.trim());
-                } else {
-                    return Err(WordParseError::ExplanationNotDefined {
-                        tag: exp_tag,
-                        word: word.inner().to_owned(),
-                    });
-                }
+/// Create word from its definition, resolving explanation tag.
+fn word(
+    syntax: &WordLine,
+    explanations: &mut HashMap<String, Definition>,
+) -> Result<Word, WordParseError> {
+    let (plain, secondary) = notation::secondary(&syntax.word)?;
+    let emphasis = text::uppercase_position(&plain)
+        .ok_or_else(|| WordParseError::EmphasisNotFound(syntax.word.clone()))?;
+    let mut word = Word::new(&plain, emphasis).with_secondary(secondary);
+    if let Some(detail) = &syntax.detail {
+        word = word.with_detail(detail);
+    }
+    if let Some(lemma) = &syntax.lemma {
+        word = word.with_form(form(lemma)?);
+    }
+    if let Some(group) = &syntax.group {
+        word = word.with_group(&group.name, group.inverted);
+    }
+    match &syntax.explanation {
+        Some(ExplanationRef::Tag(tag)) => match explanations.get_mut(&tag.to_lowercase()) {
+            Some(definition) => {
+                definition.used = true;
+                word = word.with_explanation(&definition.text);
             }
             None => {
-                let exp: String = left.chars().skip_while(|c| *c != '<').skip(1).collect();
-                if !exp.is_empty() {
-                    word = word.with_explanation(exp.trim());
-                }
+                return Err(WordParseError::ExplanationNotDefined {
+                    tag: tag.to_lowercase(),
+                    word: word.inner().to_owned(),
+                });
             }
-        };
+        },
+        Some(ExplanationRef::Text(text)) => word = word.with_explanation(text),
+        None => {}
     }
     Ok(word)
 }
 
-#[derive(Debug)]
-enum ParseResult {
-    Word(Word),
-    Explanation(String, String),
-    Error(ParseError),
+/// Explanation that the next continuation line extends.
+enum Continued {
+    /// Previous line has no explanation text.
+    Nothing,
+    /// Previous line is ignored, so are its continuations.
+    Invalid,
+    /// Definition of explanation with tag.
+    Definition(String),
+    /// Inline explanation of the latest word.
+    Word,
+}
+
+/// Explanation text with location of its definition.
+struct Definition {
+    text: String,
+    line: usize,
+    raw: String,
+    /// Explanation is referenced by at least one word.
+    used: bool,
 }
 
-impl From<Result<Word, ParseError>> for ParseResult {
-    fn from(val: Result<Word, ParseError>) -> Self {
-        match val {
-            Ok(w) => ParseResult::Word(w),
-            Err(e) => ParseResult::Error(e),
+/// Words of group with location of the first one.
+struct GroupMembers {
+    name: String,
+    line: usize,
+    raw: String,
+    positive: usize,
+    inverted: usize,
+}
+
+/// Get byte range of line without surrounding whitespace.
+fn trimmed_span(raw: &str) -> Range<usize> {
+    let start = raw.len() - raw.trim_start().len();
+    start..raw.trim_end().len()
+}
+
+/// Get byte range of the first word or quoted phrase in line.
+fn word_span(raw: &str) -> Range<usize> {
+    let start = raw.len() - raw.trim_start().len();
+    let rest = &raw[start..];
+    let len = match rest.strip_prefix('"') {
+        Some(quoted) => quoted.find('"').map_or(rest.len(), |end| end + 2),
+        None => rest.find(char::is_whitespace).unwrap_or(rest.len()),
+    };
+    start..start + len
+}
+
+/// Get byte range of explanation tag in word definition.
+fn tag_span(raw: &str) -> Range<usize> {
+    match raw.find('>') {
+        Some(marker) => {
+            let tag = &raw[marker + 1..];
+            let start = marker + 1 + tag.len() - tag.trim_start().len();
+            start..marker + 1 + tag.trim_end().len()
         }
+        None => trimmed_span(raw),
     }
 }
 
-impl From<Result<Explanation, ParseError>> for ParseResult {
-    fn from(val: Result<Explanation, ParseError>) -> Self {
-        match val {
-            Ok(exp) => ParseResult::Explanation(exp.tag, exp.text),
-            Err(e) => ParseResult::Error(e),
+/// Error in word list with location of its source.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("Line {line}, column {}: {kind}", columns.start)]
+pub struct ParseError {
+    /// One-based number of line.
+    pub line: usize,
+    /// One-based range of columns, end is exclusive.
+    pub columns: Range<usize>,
+    /// Text of whole line.
+    pub snippet: String,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Create error located at byte range `bytes` of `snippet`.
+    pub(super) fn new(
+        line: usize,
+        snippet: &str,
+        bytes: Range<usize>,
+        kind: ParseErrorKind,
+    ) -> ParseError {
+        ParseError {
+            line,
+            columns: columns(snippet, bytes),
+            snippet: snippet.to_string(),
+            kind,
         }
     }
+
+    /// Render error in the style of rustc diagnostics.
+    ///
+    /// `file` is a name of word list that is shown in location of error.
+    pub fn render(&self, file: &str) -> String {
+        render(
+            "error",
+            &self.kind,
+            file,
+            self.line,
+            &self.columns,
+            &self.snippet,
+        )
+    }
 }
 
-#[derive(Debug, Error, PartialEq, Eq)]
-#[error("Line {}: {}", line, inner)]
-pub enum ParseError {
-    WordParseError {
-        line: usize,
-        inner: WordParseError,
-    },
-    ExplanationParseError {
-        line: usize,
-        inner: ExplanationParseError,
-    },
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    #[error(transparent)]
+    Word(#[from] WordParseError),
+    #[error(transparent)]
+    Explanation(#[from] ExplanationParseError),
+    #[error(transparent)]
+    Import(#[from] ImportError),
 }
 
-impl ParseError {
-    pub fn new_word(line: usize, inner: WordParseError) -> ParseError {
-        ParseError::WordParseError { line, inner }
+/// Possible mistake in word list that doesn't prevent it from being loaded.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("Line {line}, column {}: {kind}", columns.start)]
+pub struct ParseWarning {
+    /// One-based number of line, or zero if warning refers to the whole word list.
+    pub line: usize,
+    /// One-based range of columns, end is exclusive.
+    pub columns: Range<usize>,
+    /// Text of whole line.
+    pub snippet: String,
+    pub kind: ParseWarningKind,
+}
+
+impl ParseWarning {
+    /// Create warning located at byte range `bytes` of `snippet`.
+    pub(super) fn new(
+        line: usize,
+        snippet: &str,
+        bytes: Range<usize>,
+        kind: ParseWarningKind,
+    ) -> Self {
+        ParseWarning {
+            line,
+            columns: columns(snippet, bytes),
+            snippet: snippet.to_string(),
+            kind,
+        }
     }
 
-    pub fn new_explanation(line: usize, inner: ExplanationParseError) -> ParseError {
-        ParseError::ExplanationParseError { line, inner }
+    /// Create warning that refers to the whole word list rather than its line.
+    pub(super) fn unlocated(kind: ParseWarningKind) -> Self {
+        ParseWarning {
+            line: 0,
+            columns: 1..1,
+            snippet: String::new(),
+            kind,
+        }
+    }
+
+    /// Render warning in the style of rustc diagnostics.
+    pub fn render(&self, file: &str) -> String {
+        if self.line == 0 {
+            return format!("warning: {}\n --> {file}", self.kind);
+        }
+        render(
+            "warning",
+            &self.kind,
+            file,
+            self.line,
+            &self.columns,
+            &self.snippet,
+        )
     }
 }
 
-#[derive(Debug, Error, PartialEq, Eq)]
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ParseWarningKind {
+    #[error("Explanation tag `{0}` is never used.")]
+    UnusedExplanation(String),
+    #[error("Word `{word}` is already defined at line {line}; this definition is ignored.")]
+    DuplicateWord { word: String, line: usize },
+    #[error("Word `{word}` is already defined in deck `{deck}`; this definition is ignored.")]
+    DefinedInDeck { word: String, deck: String },
+    #[error("Group `{0}` has only one word, so there is nothing to show along with it.")]
+    SingleMemberGroup(String),
+    #[error("Group `{0}` has only words marked with `!`, but no words of its rule.")]
+    OnlyInvertedGroup(String),
+}
+
+/// Convert byte range of `snippet` to one-based range of columns.
+fn columns(snippet: &str, bytes: Range<usize>) -> Range<usize> {
+    let column = |byte: usize| snippet[..byte].chars().count() + 1;
+    column(bytes.start)..column(bytes.end)
+}
+
+/// Render diagnostic in the style of rustc.
+fn render(
+    level: &str,
+    message: &impl std::fmt::Display,
+    file: &str,
+    line: usize,
+    columns: &Range<usize>,
+    snippet: &str,
+) -> String {
+    let number = line.to_string();
+    let gutter = " ".repeat(number.len());
+    let marker = format!(
+        "{}{}",
+        " ".repeat(columns.start - 1),
+        "^".repeat(columns.len().max(1))
+    );
+    format!(
+        "{level}: {message}\n{gutter}--> {file}:{line}:{column}\n{gutter} |\n{number} | {snippet}\n{gutter} | {marker}",
+        column = columns.start,
+        snippet = snippet.trim_end(),
+    )
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
 pub enum ExplanationParseError {
     #[error("`:` delimiter not found.")]
     DelimiterNotFound,
+    #[error(
+        "Line starting with `|` must follow explanation definition or word with `<` explanation."
+    )]
+    NothingToContinue,
 }
 
-#[derive(Debug, Error, PartialEq, Eq)]
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
 pub enum WordParseError {
     #[error(
         "Word must contain emphasis specified by uppercase letter; Word `{0}` has no emphasis."
@@ -160,34 +436,41 @@ pub enum WordParseError {
     ExplanationNotDefined { tag: String, word: String },
     #[error("Explanation tag not found although it was expected.")]
     NoExplanationTag,
+    #[error("Lemma not found although it was expected.")]
+    NoLemma,
     #[error("Explanation can't be empty.")]
     ExplanationEmpty,
-}
-
-/// Binding between tag and text explaining accentuation.
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Explanation {
-    tag: String,
-    text: String,
-}
-
-impl FromStr for Explanation {
-    type Err = ExplanationParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (tag, text) = s
-            .split_once(':')
-            .ok_or(ExplanationParseError::DelimiterNotFound)?;
-        Ok(Explanation {
-            tag: tag.trim().to_lowercase(),
-            text: text.trim().to_string(),
-        })
-    }
+    #[error("Phrase `{0}` has no closing quote.")]
+    UnclosedQuote(String),
+    #[error("Word `{word}` marks stress both with {first} and {second}; use only one notation.")]
+    AmbiguousStress {
+        word: String,
+        first: Notation,
+        second: Notation,
+    },
+    #[error("Word `{word}` marks stress with {found}, but word list uses {expected}.")]
+    UnexpectedNotation {
+        word: String,
+        expected: Notation,
+        found: Notation,
+    },
+    #[error("Word `{0}` has more than one stressed letter.")]
+    MultipleStress(String),
+    #[error(
+        "Word `{word}` has stress marked with {notation}, but there is no vowel it refers to."
+    )]
+    MisplacedStressMark { word: String, notation: Notation },
+    #[error("Word `{0}` has secondary stress marked on consonant or on the stressed letter.")]
+    MisplacedSecondaryStress(String),
 }
 
 #[cfg(test)]
 mod test {
-    use crate::model::{parse::parse, Word};
+    use crate::model::{
+        parse::{parse, ExplanationParseError, ParseErrorKind, ParseWarningKind, WordParseError},
+        word::WordForm,
+        Word,
+    };
 
     #[test]
     fn test_detail() {
@@ -201,10 +484,45 @@ mod test {
                 Word::new("отзыв", 0).with_detail("(о книге)"),
             ],
             Vec::new(),
+            Vec::new(),
         );
         assert_eq!(parse(data), correct);
     }
 
+    #[test]
+    fn test_lemma() {
+        let data = "
+        нОгтя = нОготь (р.п.) : НОГОТЬ
+        кОнусов (мн.ч.) = ко'нус
+        тОртов =
+        ";
+        let (words, errors, _) = parse(data);
+        assert_eq!(
+            words,
+            vec![
+                Word::new("ногтя", 1)
+                    .with_form(WordForm {
+                        lemma: String::from("ноготь"),
+                        emphasis: 1,
+                        name: Some(String::from("(р.п.)")),
+                    })
+                    .with_group("НОГОТЬ", false),
+                Word::new("конусов", 1)
+                    .with_detail("(мн.ч.)")
+                    .with_form(WordForm {
+                        lemma: String::from("конус"),
+                        emphasis: 1,
+                        name: None,
+                    }),
+            ]
+        );
+        assert_eq!(words[1].lemma(), ("конус", 1));
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::Word(WordParseError::NoLemma)
+        );
+    }
+
     #[test]
     fn test_group() {
         let data = "
@@ -219,10 +537,36 @@ mod test {
                 Word::new("нефтепровод", 9).with_group("ПРОВОД", false),
             ],
             Vec::new(),
+            Vec::new(),
         );
         assert_eq!(parse(data), correct);
     }
 
+    #[test]
+    fn test_secondary_stress() {
+        let data = "
+        га`зопровОд
+        не\u{300}фтепрово\u{301}д
+        ";
+        let (words, errors, _) = parse(data);
+        assert!(errors.is_empty());
+        assert_eq!(
+            words,
+            vec![
+                Word::new("газопровод", 8).with_secondary(vec![1]),
+                Word::new("нефтепровод", 9).with_secondary(vec![1]),
+            ]
+        );
+
+        let (_, errors, _) = parse("газопр`овОд");
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::Word(WordParseError::MisplacedSecondaryStress(String::from(
+                "газопр\u{300}овОд"
+            )))
+        );
+    }
+
     #[test]
     fn test_explanation() {
         let data = "
@@ -232,7 +576,140 @@ mod test {
         let correct = (
             vec![Word::new("слово", 2).with_explanation("Просто проверка работоспособности.")],
             Vec::new(),
+            Vec::new(),
         );
         assert_eq!(parse(data), correct);
     }
+
+    #[test]
+    fn test_multiline_explanation() {
+        let data = "
+        > ПРОВЕРКА: Первая строка.
+          | Вторая строка.
+        слОво > ПРОВЕРКА
+        гОрод < Первая строка.
+          | Вторая строка.
+
+          | Продолжать нечего.
+        ";
+        let (words, errors, _) = parse(data);
+        let explanation = "Первая строка.\nВторая строка.";
+        assert_eq!(
+            words,
+            vec![
+                Word::new("слово", 2).with_explanation(explanation),
+                Word::new("город", 1).with_explanation(explanation),
+            ]
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, 8);
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::Explanation(ExplanationParseError::NothingToContinue)
+        );
+    }
+
+    #[test]
+    fn test_phrase() {
+        let data = r#"
+        "нА  дом" (задать) : ПРЕДЛОГ
+        "пО морю" : ПРЕДЛОГ
+        "пО морю"
+        "дО смерти
+        "#;
+        let (words, errors, warnings) = parse(data);
+        assert_eq!(
+            words,
+            vec![
+                Word::new("на дом", 1)
+                    .with_detail("(задать)")
+                    .with_group("ПРЕДЛОГ", false),
+                Word::new("по морю", 1).with_group("ПРЕДЛОГ", false),
+            ]
+        );
+        assert_eq!(words[0].variants().len(), 2);
+        assert_eq!(words[1].to_string(), "пО морю");
+        assert_eq!(warnings[0].columns, 9..18);
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::Word(WordParseError::UnclosedQuote(String::from("дО смерти")))
+        );
+    }
+
+    #[test]
+    fn test_category() {
+        let data = "
+        свЁкла
+        # Глаголы
+        брАть
+        ";
+        let correct = (
+            vec![
+                Word::new("свёкла", 2),
+                Word::new("брать", 2).with_category("Глаголы"),
+            ],
+            Vec::new(),
+            Vec::new(),
+        );
+        assert_eq!(parse(data), correct);
+    }
+
+    #[test]
+    fn test_error_span() {
+        let data = "слОво\n  гОрод > НЕТ\nкот";
+        let (words, errors, _) = parse(data);
+        assert_eq!(words, vec![Word::new("слово", 2)]);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].line, 2);
+        assert_eq!(errors[0].columns, 11..14);
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::Word(WordParseError::ExplanationNotDefined {
+                tag: String::from("нет"),
+                word: String::from("город"),
+            })
+        );
+        assert_eq!((errors[1].line, errors[1].columns.clone()), (3, 1..4));
+    }
+
+    #[test]
+    fn test_error_render() {
+        let (_, errors, _) = parse("слОво : А ! Б");
+        let rendered = errors[0].render("custom.txt");
+        let expected = "\
+error: Word `слово` has multiple groups defined. Currently only one group allowed.
+ --> custom.txt:1:11
+  |
+1 | слОво : А ! Б
+  |           ^^^";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_warnings() {
+        let data = "
+        > НЕТ: Не используется.
+        слОво
+        слОво
+        кремЕнь : КРЕМЕНЬ
+        прОвод ! ПРОВОД
+        провОд (воды) ! ПРОВОД
+        ";
+        let (words, errors, warnings) = parse(data);
+        assert_eq!(words.len(), 4);
+        assert!(errors.is_empty());
+        let kinds = warnings.into_iter().map(|w| w.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                ParseWarningKind::UnusedExplanation(String::from("нет")),
+                ParseWarningKind::DuplicateWord {
+                    word: String::from("слОво"),
+                    line: 3,
+                },
+                ParseWarningKind::SingleMemberGroup(String::from("КРЕМЕНЬ")),
+                ParseWarningKind::OnlyInvertedGroup(String::from("ПРОВОД")),
+            ]
+        );
+    }
 }
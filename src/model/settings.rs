@@ -0,0 +1,161 @@
+use gloo::storage::{errors::StorageError, LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+use super::StressStyle;
+
+/// User preferences.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Number of cards in session. Zero means that session is unlimited.
+    pub session_length: u32,
+    /// Order in which words are shown.
+    pub order: CardOrder,
+    /// Show success card after correct answer.
+    pub success_card: bool,
+    /// Time before success card moves to the next word, in seconds. Zero disables auto-advance.
+    pub success_delay: u32,
+    /// Allow to undo the last answer.
+    pub undo: bool,
+    /// Show skip button on question card.
+    pub skip: bool,
+    /// Whether skipped word is counted as failed one.
+    pub skip_is_failure: bool,
+    /// Show session timer in card header.
+    pub timer: bool,
+    /// Color theme.
+    pub theme: Theme,
+    /// Way to show stressed letter.
+    pub stress: StressStyle,
+    /// Way to show variants of answer.
+    pub variants: VariantLayout,
+    /// Identifiers of decks to train on.
+    pub selected_decks: Vec<String>,
+}
+
+impl Settings {
+    const KEY: &'static str = "settings";
+    /// Longest session that may be set, in cards.
+    pub const MAX_SESSION_LENGTH: u32 = 1000;
+    /// Longest delay before success card moves on, in seconds.
+    pub const MAX_SUCCESS_DELAY: u32 = 60;
+
+    /// Load settings from LocalStorage, falling back to default ones.
+    pub fn load() -> Self {
+        match LocalStorage::get(Self::KEY) {
+            Ok(settings) => settings,
+            Err(StorageError::KeyNotFound(_) | StorageError::SerdeError(_)) => Self::default(),
+            Err(StorageError::JsError(e)) => {
+                gloo::console::error!(format!("LocalStorage is not functional {e}"));
+                Self::default()
+            }
+        }
+    }
+
+    /// Save settings to LocalStorage.
+    pub fn save(&self) {
+        if let Err(e) = LocalStorage::set(Self::KEY, self) {
+            gloo::console::error!(format!("Failed to save settings: {e}"));
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            session_length: 0,
+            order: CardOrder::Random,
+            success_card: false,
+            success_delay: 3,
+            undo: true,
+            skip: true,
+            skip_is_failure: true,
+            timer: true,
+            theme: Theme::System,
+            stress: StressStyle::Uppercase,
+            variants: VariantLayout::Words,
+            selected_decks: vec![String::from("ege")],
+        }
+    }
+}
+
+/// Order in which words are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CardOrder {
+    /// Any word may be shown next.
+    Random,
+    /// Words that are due for repetition are shown first.
+    Scheduled,
+    /// Words are shown in the order they are defined.
+    Sequential,
+}
+
+impl CardOrder {
+    pub const ALL: [CardOrder; 3] = [
+        CardOrder::Random,
+        CardOrder::Scheduled,
+        CardOrder::Sequential,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardOrder::Random => "Случайный",
+            CardOrder::Scheduled => "Интервальное повторение",
+            CardOrder::Sequential => "По порядку",
+        }
+    }
+}
+
+/// Way to show variants of answer on question card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VariantLayout {
+    /// Whole word for each vowel.
+    Words,
+    /// Word split into syllables, stressed one is chosen.
+    Syllables,
+}
+
+impl VariantLayout {
+    pub const ALL: [VariantLayout; 2] = [VariantLayout::Words, VariantLayout::Syllables];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            VariantLayout::Words => "Слово целиком",
+            VariantLayout::Syllables => "По слогам",
+        }
+    }
+}
+
+/// Color theme of application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Theme {
+    /// Follow theme and contrast preferred by system.
+    System,
+    Light,
+    Dark,
+    /// Black on white, answer is shown with text and border besides color.
+    Contrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 4] = [Theme::System, Theme::Light, Theme::Dark, Theme::Contrast];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::System => "Системная",
+            Theme::Light => "Светлая",
+            Theme::Dark => "Тёмная",
+            Theme::Contrast => "Контрастная",
+        }
+    }
+
+    /// Value of `data-theme` attribute of document.
+    pub fn attribute(&self) -> &'static str {
+        match self {
+            Theme::System => "system",
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::Contrast => "contrast",
+        }
+    }
+}
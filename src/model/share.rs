@@ -0,0 +1,60 @@
+//! Compact encoding of word lists that allows to share them via link.
+
+use miniz_oxide::inflate::{self, TINFLStatus};
+use thiserror::Error;
+
+/// Maximal size of decoded word list, so small link can't take up all memory.
+const MAX_SOURCE_LEN: usize = 1 << 20;
+
+/// Encode source of word list into string that may be used in URL.
+pub fn encode(source: &str) -> String {
+    let compressed = miniz_oxide::deflate::compress_to_vec(source.as_bytes(), 9);
+    base64::encode_config(compressed, base64::URL_SAFE_NO_PAD)
+}
+
+/// Decode source of word list [encoded](encode) into string.
+pub fn decode(payload: &str) -> Result<String, ShareError> {
+    let compressed = base64::decode_config(payload.trim(), base64::URL_SAFE_NO_PAD)?;
+    let bytes =
+        inflate::decompress_to_vec_with_limit(&compressed, MAX_SOURCE_LEN).map_err(|status| {
+            match status {
+                TINFLStatus::HasMoreOutput => ShareError::TooLarge,
+                _ => ShareError::Decompress,
+            }
+        })?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+#[derive(Debug, Error)]
+pub enum ShareError {
+    #[error("Link is damaged: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("Link is damaged: data can't be decompressed")]
+    Decompress,
+    #[error("Word list in link is larger than {} KiB", MAX_SOURCE_LEN / 1024)]
+    TooLarge,
+    #[error("Link is damaged: {0}")]
+    Utf8(#[from] std::string::FromUtf8Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, encode, ShareError, MAX_SOURCE_LEN};
+
+    #[test]
+    fn test_encode_roundtrip() {
+        let text = "# Глаголы\nбралА < Прош. вр. ж. р.\nзвонИт\n";
+        let payload = encode(text);
+        assert!(payload
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(decode(&payload).unwrap(), text);
+        assert!(decode("не ссылка").is_err());
+    }
+
+    #[test]
+    fn test_decode_limit() {
+        let payload = encode(&"а".repeat(MAX_SOURCE_LEN));
+        assert!(matches!(decode(&payload), Err(ShareError::TooLarge)));
+    }
+}
@@ -1,28 +1,65 @@
-use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Local, TimeZone, Utc};
 use gloo::storage::{errors::StorageError, LocalStorage, Storage};
 use indexmap::IndexMap;
 use rand::seq::IteratorRandom;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use super::{CardResult, WordHash};
+use super::{CardOrder, CardResult, WordHash};
 
 /// Stats struct stores mapping between word and its progression.
-pub struct Stats(IndexMap<WordHash, Record>);
+#[derive(Default)]
+pub struct Stats {
+    /// Records of all words ever trained, including ones from decks that aren't selected.
+    records: IndexMap<WordHash, Record>,
+    /// Words that may be shown, in sequential order that starts as the order they are defined.
+    words: Vec<WordHash>,
+    /// Position of the next word in sequential order.
+    cursor: usize,
+}
 
 impl Stats {
     const KEY: &'static str = "words-stats";
 
-    pub fn new(words: Vec<WordHash>) -> Self {
-        let new: IndexMap<WordHash, Record> =
-            words.into_iter().map(|h| (h, Record::default())).collect();
-        let mut new = Stats(new);
+    /// Create statistics of `words`, keeping records stored in LocalStorage.
+    ///
+    /// Records stored under old hashes of words are moved to hashes `renamed` maps them to.
+    pub fn new(words: Vec<WordHash>, renamed: &HashMap<WordHash, WordHash>) -> Self {
+        let new = Self::with_records(Self::load(), words, renamed);
         new.sync();
         new
     }
 
+    /// Create statistics of `words` from previously kept records.
+    ///
+    /// Only words that have no record yet get a new one.
+    pub(super) fn with_records(
+        mut records: IndexMap<WordHash, Record>,
+        words: Vec<WordHash>,
+        renamed: &HashMap<WordHash, WordHash>,
+    ) -> Self {
+        for (old, &new) in renamed {
+            if records.contains_key(&new) {
+                continue;
+            }
+            if let Some(record) = records.swap_remove(old) {
+                records.insert(new, record);
+            }
+        }
+        for &word in &words {
+            records.entry(word).or_default();
+        }
+        Stats {
+            records,
+            words,
+            cursor: 0,
+        }
+    }
+
     /// Load statistics from LocalStorage.
-    fn load() -> Self {
+    fn load() -> IndexMap<WordHash, Record> {
         let stored = match LocalStorage::get(Self::KEY) {
             Ok(val) => val,
             Err(StorageError::KeyNotFound(_) | StorageError::SerdeError(_)) => {
@@ -39,44 +76,165 @@ impl Stats {
             }
             Err(StorageError::JsError(e)) => panic!("JS error occured: {}", e),
         };
-        Stats(stored)
+        stored
     }
 
-    /// Get a following word to train by.
-    pub fn next(&mut self) -> WordHash {
-        if let Some((word, record)) = self.0.iter_mut().choose(&mut rand::thread_rng()) {
+    /// Get a following word to train by, preferring ones that aren't `avoided`.
+    ///
+    /// Only the word that is returned is marked as shown and moves sequential order forward.
+    /// Returns `None` if there is no word that may be shown.
+    pub fn next(
+        &mut self,
+        order: CardOrder,
+        avoided: impl Fn(WordHash) -> bool,
+    ) -> Option<WordHash> {
+        let (index, word) = self
+            .pick(order, &avoided)
+            .or_else(|| self.pick(order, &|_| false))?;
+        if order == CardOrder::Sequential {
+            // Chosen word is moved in front of skipped ones, so they are shown right after it.
+            let mut start = self.cursor % self.words.len();
+            if index < start {
+                start -= 1;
+            }
+            let word = self.words.remove(index);
+            self.words.insert(start, word);
+            self.cursor = start + 1;
+        }
+        if let Some(record) = self.records.get_mut(&word) {
             record.occured();
-            *word
-        } else {
-            panic!("Word list is empty.");
+        }
+        Some(word)
+    }
+
+    /// Choose word that may be shown along with its index, without changing anything.
+    fn pick(
+        &self,
+        order: CardOrder,
+        avoided: &dyn Fn(WordHash) -> bool,
+    ) -> Option<(usize, WordHash)> {
+        let mut rng = rand::thread_rng();
+        let records = &self.records;
+        let available = |word: WordHash| {
+            !avoided(word) && records.get(&word).map(Record::is_active).unwrap_or(false)
+        };
+        let active = self
+            .words
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, h)| available(h));
+        match order {
+            CardOrder::Random => active.choose(&mut rng),
+            CardOrder::Scheduled => {
+                let active = active.map(|(i, h)| (i, h, records[&h])).collect::<Vec<_>>();
+                let lowest = active.iter().map(|(_, _, r)| r.group).min()?;
+                let due = active.iter().any(|(_, _, r)| r.is_due());
+                active
+                    .into_iter()
+                    .filter(|(_, _, r)| if due { r.is_due() } else { r.group == lowest })
+                    .map(|(i, h, _)| (i, h))
+                    .choose(&mut rng)
+            }
+            CardOrder::Sequential => {
+                let len = self.words.len();
+                (0..len)
+                    .map(|offset| (self.cursor + offset) % len)
+                    .map(|i| (i, self.words[i]))
+                    .find(|&(_, h)| available(h))
+            }
         }
     }
 
     /// Update priority of word depending on card pass result.
-    pub fn passed(&mut self, word: WordHash, result: CardResult) {
-        if let Some(record) = self.0.get_mut(&word) {
-            match result {
-                CardResult::Solved => {
-                    record.group.promote();
-                }
-                CardResult::Failed => {
-                    record.group.demote();
-                }
+    ///
+    /// Returns snapshot of the progress before update that may be used to [revert](Stats::revert) it.
+    pub fn passed(&mut self, word: WordHash, result: CardResult) -> Option<Snapshot> {
+        let record = self.records.get_mut(&word)?;
+        let snapshot = Snapshot {
+            word,
+            last_occured: record.last_occured,
+            group: record.group,
+        };
+        match result {
+            CardResult::Solved => {
+                record.group.promote();
+            }
+            CardResult::Failed => {
+                record.group.demote();
             }
-            self.sync()
         }
+        self.sync();
+        Some(snapshot)
+    }
+
+    /// Get group of word.
+    pub fn group(&self, word: WordHash) -> Option<Group> {
+        self.records.get(&word).map(|r| r.group)
+    }
+
+    /// Returns true if word was never shown or should be repeated.
+    pub fn is_due(&self, word: WordHash) -> bool {
+        self.records.get(&word).map(Record::is_due).unwrap_or(true)
+    }
+
+    /// Get state of word set by user.
+    pub fn state(&self, word: WordHash) -> WordState {
+        self.records
+            .get(&word)
+            .map(|r| WordState {
+                suspended: r.suspended,
+                buried: r.is_buried(),
+                flagged: r.flagged,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Exclude word from training or return it back.
+    pub fn set_suspended(&mut self, word: WordHash, suspended: bool) {
+        self.update(word, |record| record.suspended = suspended);
+    }
+
+    /// Exclude word from training until tomorrow.
+    pub fn bury(&mut self, word: WordHash) {
+        let now = Local::now();
+        let tomorrow = now
+            .naive_local()
+            .date()
+            .succ_opt()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .and_then(|midnight| Local.from_local_datetime(&midnight).single())
+            .unwrap_or(now + Duration::days(1));
+        self.update(word, |record| {
+            record.buried_until = Some(tomorrow.with_timezone(&Utc))
+        });
+    }
+
+    /// Mark word as one with possibly incorrect data or remove the mark.
+    pub fn set_flagged(&mut self, word: WordHash, flagged: bool) {
+        self.update(word, |record| record.flagged = flagged);
     }
 
-    fn sync(&mut self) {
-        let current = &self.0;
-        let mut stored = Self::load().0;
-        if current == &stored {
-            return;
+    fn update(&mut self, word: WordHash, f: impl FnOnce(&mut Record)) {
+        if let Some(record) = self.records.get_mut(&word) {
+            f(record);
+            self.sync();
         }
-        stored.extend(current);
-        self.0 = stored;
+    }
 
-        match LocalStorage::set(Self::KEY, &self.0) {
+    /// Restore progress of word to the one it had when snapshot was taken.
+    ///
+    /// State set by user since then, like suspension, is kept.
+    pub fn revert(&mut self, snapshot: Snapshot) {
+        self.update(snapshot.word, |record| {
+            record.last_occured = snapshot.last_occured;
+            record.group = snapshot.group;
+        });
+    }
+
+    /// Save records to LocalStorage.
+    fn sync(&self) {
+        match LocalStorage::set(Self::KEY, &self.records) {
             Ok(_) => {}
             Err(StorageError::KeyNotFound(_)) => unreachable!(),
             Err(StorageError::SerdeError(e)) => panic!("Serde error occured: {}", e),
@@ -94,12 +252,37 @@ pub enum StatisticsError {
     Invalid,
 }
 
+/// State of word set by user.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WordState {
+    /// Word is never shown.
+    pub suspended: bool,
+    /// Word isn't shown until tomorrow.
+    pub buried: bool,
+    /// User thinks that word data is wrong.
+    pub flagged: bool,
+}
+
+/// Progress of word before answer changed it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot {
+    word: WordHash,
+    last_occured: Option<DateTime<Utc>>,
+    group: Group,
+}
+
 /// Record contains statistical data about one word.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(default)]
-struct Record {
+pub(super) struct Record {
     last_occured: Option<DateTime<Utc>>,
     group: Group,
+    /// Word is never shown.
+    suspended: bool,
+    /// Word isn't shown until that moment.
+    buried_until: Option<DateTime<Utc>>,
+    /// User thinks that word data is wrong.
+    flagged: bool,
 }
 
 impl Record {
@@ -108,6 +291,9 @@ impl Record {
         Self {
             last_occured: None,
             group: Group::new(),
+            suspended: false,
+            buried_until: None,
+            flagged: false,
         }
     }
     /// Update inner timer of record.
@@ -115,6 +301,23 @@ impl Record {
         self.last_occured = Some(Utc::now())
     }
 
+    /// Returns true if word may be shown.
+    pub fn is_active(&self) -> bool {
+        !self.suspended && !self.is_buried()
+    }
+
+    /// Returns true if word is hidden until later moment.
+    pub fn is_buried(&self) -> bool {
+        self.buried_until
+            .map(|until| until > Utc::now())
+            .unwrap_or(false)
+    }
+
+    /// Returns true if word was never shown or should be repeated.
+    pub fn is_due(&self) -> bool {
+        self.last_occured.is_none() || self.should_repeat()
+    }
+
     /// Returns true if time since last word repetition is longer than repetition period.
     pub fn should_repeat(&self) -> bool {
         let now = Utc::now();
@@ -136,10 +339,18 @@ impl Record {
 pub struct Group(u8);
 
 impl Group {
+    /// Number of groups.
+    pub const COUNT: usize = 8;
+
     pub fn new() -> Self {
         Group(0)
     }
 
+    /// Get zero-based level of group.
+    pub fn level(&self) -> u8 {
+        self.0
+    }
+
     pub fn promote(&mut self) {
         if self.0 < 7 {
             self.0 += 1;
@@ -164,3 +375,45 @@ impl Group {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use indexmap::IndexMap;
+
+    use super::{Record, Stats};
+    use crate::model::Word;
+
+    /// Test that state set by user and progress survive reload of decks.
+    #[test]
+    fn test_records_kept_on_reload() {
+        let word = Word::new("слово", 2).hash();
+        let added = Word::new("город", 1).hash();
+        let mut stats = Stats::with_records(IndexMap::new(), vec![word], &HashMap::new());
+        let record = &mut stats.records[&word];
+        record.group.promote();
+        record.suspended = true;
+        record.flagged = true;
+        let expected = *record;
+
+        let reloaded = Stats::with_records(stats.records, vec![word, added], &HashMap::new());
+        assert_eq!(reloaded.records[&word], expected);
+        assert_eq!(reloaded.records[&added], Default::default());
+    }
+
+    /// Test that progress stored under hash of word without its detail is moved to current hash.
+    #[test]
+    fn test_records_moved_to_renamed_words() {
+        let word = Word::new("замок", 1).with_detail("(дворец)");
+        let legacy = word.legacy_hash().unwrap();
+        let mut record = Record::default();
+        record.group.promote();
+        let stored = IndexMap::from([(legacy, record)]);
+
+        let renamed = HashMap::from([(legacy, word.hash())]);
+        let stats = Stats::with_records(stored, vec![word.hash()], &renamed);
+        assert_eq!(stats.records[&word.hash()], record);
+        assert!(!stats.records.contains_key(&legacy));
+    }
+}
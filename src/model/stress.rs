@@ -0,0 +1,151 @@
+//! Rendering of word with stressed letter, shared by words, variants and exports.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::text;
+
+use super::notation::GRAVE;
+
+/// Way to show stressed letter to user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StressStyle {
+    /// Stressed letter is uppercase, like in exam: `молОко`.
+    Uppercase,
+    /// Combining acute accent after stressed letter, like in dictionaries: `моло́ко`.
+    Accent,
+    /// Stressed letter is bold and colored. Plain text falls back to uppercase.
+    Highlight,
+}
+
+impl StressStyle {
+    pub const ALL: [StressStyle; 3] = [
+        StressStyle::Uppercase,
+        StressStyle::Accent,
+        StressStyle::Highlight,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StressStyle::Uppercase => "Заглавная буква",
+            StressStyle::Accent => "Знак ударения",
+            StressStyle::Highlight => "Выделение цветом",
+        }
+    }
+}
+
+/// Word with stressed letter and optional detail, written in chosen style.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stressed {
+    /// Word in lowercase, with secondary stress marked by combining grave accent.
+    pub word: String,
+    /// Position of stressed letter.
+    pub position: usize,
+    pub detail: Option<String>,
+    pub style: StressStyle,
+}
+
+impl Stressed {
+    pub fn new(word: &str, position: usize, style: StressStyle) -> Self {
+        Stressed {
+            word: word.to_string(),
+            position,
+            detail: None,
+            style,
+        }
+    }
+
+    /// Mark letters at `positions` with secondary stress.
+    pub fn with_secondary(mut self, positions: &[usize]) -> Self {
+        self.word = text::letters(&self.word)
+            .into_iter()
+            .enumerate()
+            .map(|(pos, letter)| match positions.contains(&pos) {
+                true => format!("{letter}{GRAVE}"),
+                false => letter.to_string(),
+            })
+            .collect();
+        self
+    }
+
+    pub fn with_detail(mut self, detail: Option<&str>) -> Self {
+        self.detail = detail.map(String::from);
+        self
+    }
+
+    /// Split word into text before stressed letter, the letter itself and text after it.
+    pub fn parts(&self) -> (&str, &str, &str) {
+        text::split_at_letter(&self.word, self.position)
+    }
+}
+
+impl Display for Stressed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.style {
+            StressStyle::Uppercase | StressStyle::Highlight => {
+                write!(f, "{}", text::uppercase_letter(&self.word, self.position))?
+            }
+            StressStyle::Accent => {
+                let (before, letter, after) = self.parts();
+                write!(f, "{before}{letter}\u{301}{after}")?
+            }
+        }
+        match &self.detail {
+            Some(detail) => write!(f, " {detail}"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::{StressStyle, Stressed};
+    use crate::{model::Notation, text};
+
+    #[test]
+    fn test_styles() {
+        let stressed = |style| Stressed::new("молоко", 3, style).with_detail(Some("(коровье)"));
+        assert_eq!(
+            stressed(StressStyle::Uppercase).to_string(),
+            "молОко (коровье)"
+        );
+        assert_eq!(
+            stressed(StressStyle::Accent).to_string(),
+            "моло\u{301}ко (коровье)"
+        );
+        assert_eq!(
+            stressed(StressStyle::Highlight).to_string(),
+            "молОко (коровье)"
+        );
+        assert_eq!(stressed(StressStyle::Highlight).parts(), ("мол", "о", "ко"));
+
+        let secondary = |style| Stressed::new("газопровод", 8, style).with_secondary(&[1]);
+        assert_eq!(
+            secondary(StressStyle::Uppercase).to_string(),
+            "га\u{300}зопровОд"
+        );
+        assert_eq!(
+            secondary(StressStyle::Accent).to_string(),
+            "га\u{300}зопрово\u{301}д"
+        );
+    }
+
+    proptest! {
+        /// Word written in any style is parsed back with the same stress.
+        #[test]
+        fn test_written_stress_roundtrip(word in "[а-яё]{1,8}(-[а-яё]{1,8})?", vowel in 0usize..8) {
+            let vowels = text::vowel_positions(&word);
+            prop_assume!(!vowels.is_empty());
+            let position = vowels[vowel % vowels.len()];
+            let uppercase = Stressed::new(&word, position, StressStyle::Uppercase).to_string();
+            for style in StressStyle::ALL {
+                let written = Stressed::new(&word, position, style).to_string();
+                prop_assert_eq!(Notation::Auto.normalize(&written), Ok(uppercase.clone()));
+            }
+            prop_assert_eq!(text::uppercase_position(&uppercase), Some(position));
+        }
+    }
+}
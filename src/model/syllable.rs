@@ -0,0 +1,108 @@
+//! Division of Russian words into syllables.
+//!
+//! Every syllable has exactly one vowel, so syllable at index `i` is the one with `i`-th vowel.
+//! Consonants between vowels usually start the next syllable (`мо-ло-ко`), except for:
+//! - `й` and sonorants before another consonant, which end the previous one (`бой-кий`, `кар-та`);
+//! - doubled consonants, which are split (`кас-са`);
+//! - `ь` and `ъ`, which stay with consonant before them (`паль-то`);
+//! - spaces and hyphens, which end the previous syllable (`по-мо-рю`).
+
+use crate::text;
+
+/// Consonants that end syllable when followed by another consonant.
+const SONORANTS: [&str; 5] = ["й", "р", "л", "м", "н"];
+
+/// Split word into syllables.
+///
+/// Syllables joined together make the whole word. Word without vowels is a single syllable.
+pub fn syllables(word: &str) -> Vec<&str> {
+    let letters = text::letters(word);
+    let vowels = text::vowel_positions(word);
+    // Letter positions where syllables start, except the first one.
+    let starts = vowels
+        .windows(2)
+        .map(|pair| pair[0] + 1 + boundary(&letters[pair[0] + 1..pair[1]]));
+
+    let mut offsets = Vec::with_capacity(letters.len() + 1);
+    let mut offset = 0;
+    for letter in &letters {
+        offsets.push(offset);
+        offset += letter.len();
+    }
+    offsets.push(offset);
+
+    let mut syllables = Vec::with_capacity(vowels.len().max(1));
+    let mut start = 0;
+    for next in starts {
+        syllables.push(&word[offsets[start]..offsets[next]]);
+        start = next;
+    }
+    syllables.push(&word[offsets[start]..]);
+    syllables
+}
+
+/// Get number of letters of cluster between two vowels that belong to the first syllable.
+fn boundary(cluster: &[&str]) -> usize {
+    let lower = cluster
+        .iter()
+        .map(|letter| text::lowercase(letter))
+        .collect::<Vec<_>>();
+    let is_letter = |l: &str| l.chars().next().is_some_and(char::is_alphabetic);
+    let is_sign = |l: &str| l == "ь" || l == "ъ";
+
+    // Words of phrase are never split.
+    if let Some(last) = lower.iter().rposition(|l| !is_letter(l)) {
+        return last + 1;
+    }
+    match lower.as_slice() {
+        // Sign before vowel starts the next syllable with its consonant: `се-мья`.
+        [.., consonant, sign] if is_sign(sign) && !is_sign(consonant) => lower.len() - 2,
+        [first, sign, _, ..] if is_sign(sign) => 2 + usize::from(is_sign(first)),
+        [first, second, ..] if first == second => 1,
+        [first, second, ..] if SONORANTS.contains(&first.as_str()) && !is_sign(second) => 1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::syllables;
+    use crate::{model::Deck, text};
+
+    #[test]
+    fn test_syllables() {
+        let cases: [(&str, &[&str]); 9] = [
+            ("молоко", &["мо", "ло", "ко"]),
+            ("бойкий", &["бой", "кий"]),
+            ("карта", &["кар", "та"]),
+            ("касса", &["кас", "са"]),
+            ("пальто", &["паль", "то"]),
+            ("семья", &["се", "мья"]),
+            (
+                "вероисповедание",
+                &["ве", "ро", "и", "спо", "ве", "да", "ни", "е"],
+            ),
+            ("по морю", &["по ", "мо", "рю"]),
+            ("вдрызг", &["вдрызг"]),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(syllables(word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_builtin_syllables() {
+        for deck in Deck::builtin() {
+            let (words, _, _) = deck.words();
+            for word in words {
+                let syllables = syllables(word.inner());
+                assert_eq!(syllables.concat(), word.inner());
+                assert_eq!(syllables.len(), word.variants().len(), "{word}");
+                for syllable in syllables {
+                    let vowels = text::vowel_positions(syllable).len();
+                    assert_eq!(vowels, 1, "{word}: `{syllable}`");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Syntax of word list lines, as they are written.
+
+use std::{fmt::Display, ops::Range};
+
+use super::{
+    notation::Notation,
+    parse::{ExplanationParseError, ParseErrorKind, WordParseError},
+};
+
+/// Value with byte range of line it refers to.
+pub type Spanned<T> = (T, Range<usize>);
+
+/// Single line of word list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Blank,
+    /// Text after `//`.
+    Comment(String),
+    /// Name of section the following words belong to.
+    Section(String),
+    /// Definition of explanation that words may refer to by its tag.
+    Explanation {
+        tag: String,
+        text: String,
+    },
+    Word(WordLine),
+    /// Text after `|` that continues explanation of the previous line.
+    Continuation(String),
+}
+
+/// Definition of word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordLine {
+    /// Word with emphasis specified by uppercase letter.
+    /// Phrase of several words is written in quotes and has single spaces between words.
+    pub word: String,
+    /// Word as it is written, with stress in notation of word list.
+    pub written: String,
+    pub detail: Option<String>,
+    pub lemma: Option<LemmaRef>,
+    pub group: Option<GroupRef>,
+    pub explanation: Option<ExplanationRef>,
+}
+
+/// Lemma that word is a form of, after `=`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LemmaRef {
+    /// Lemma with emphasis specified by uppercase letter.
+    pub word: String,
+    /// Lemma as it is written, with stress in notation of word list.
+    pub written: String,
+    /// Grammatical form of word, as it is written.
+    pub form: Option<String>,
+}
+
+/// Group that word belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupRef {
+    /// Name of group as it is written.
+    pub name: String,
+    /// Word doesn't follow the rule of group, marked with `!`.
+    pub inverted: bool,
+}
+
+/// Explanation of word's emphasis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExplanationRef {
+    /// Tag of explanation defined elsewhere, after `>`.
+    Tag(String),
+    /// Explanation text, after `<`.
+    Text(String),
+}
+
+impl Line {
+    /// Parse line of word list, normalizing stress in `notation` to uppercase letter.
+    ///
+    /// Words are kept as they are written as well, so line may be written back in its notation.
+    ///
+    /// On failure returns error with byte range of `raw` that caused it.
+    pub fn parse(raw: &str, notation: Notation) -> Result<Line, Spanned<ParseErrorKind>> {
+        let text = raw.trim();
+        // Spans are found in trimmed text, but reported in raw one.
+        let indent = raw.len() - raw.trim_start().len();
+        let spanned = |kind: ParseErrorKind, span: Range<usize>| {
+            (kind, indent + span.start..indent + span.end)
+        };
+
+        if text.is_empty() {
+            Ok(Line::Blank)
+        } else if let Some(comment) = text.strip_prefix("//") {
+            Ok(Line::Comment(comment.to_string()))
+        } else if let Some(name) = text.strip_prefix('#') {
+            Ok(Line::Section(name.trim().to_string()))
+        } else if let Some(text) = text.strip_prefix('|') {
+            Ok(Line::Continuation(text.trim().to_string()))
+        } else if let Some(rest) = text.strip_prefix('>') {
+            let (tag, text) = rest.split_once(':').ok_or_else(|| {
+                let kind = ExplanationParseError::DelimiterNotFound.into();
+                spanned(kind, 0..text.len())
+            })?;
+            Ok(Line::Explanation {
+                tag: tag.trim().to_string(),
+                text: text.trim().to_string(),
+            })
+        } else {
+            WordLine::parse(text, notation)
+                .map(Line::Word)
+                .map_err(|(e, span)| spanned(e.into(), span))
+        }
+    }
+}
+
+impl WordLine {
+    /// Parse trimmed line with word definition.
+    ///
+    /// On failure returns error with byte range of `line` that caused it.
+    fn parse(line: &str, notation: Notation) -> Result<WordLine, Spanned<WordParseError>> {
+        let (word, word_end) = match line.strip_prefix('"') {
+            Some(quoted) => match quoted.find('"') {
+                Some(end) => (&quoted[..end], end + 2),
+                None => {
+                    let err = WordParseError::UnclosedQuote(quoted.to_string());
+                    return Err((err, 0..line.len()));
+                }
+            },
+            None => {
+                let end = line.find(char::is_whitespace).unwrap_or(line.len());
+                (&line[..end], end)
+            }
+        };
+        let word = word.split_whitespace().collect::<Vec<_>>().join(" ");
+        let normalized = notation.normalize(&word).map_err(|e| (e, 0..word_end))?;
+
+        let left = &line[word_end..];
+        // Explanation starts with `>` or `<`, anything before it is detail and group.
+        let body_end = left.find(&['>', '<'][..]).unwrap_or(left.len());
+        let body = &left[..body_end];
+
+        // Detail
+        let detail_end = body.find(&[':', '!', '='][..]).unwrap_or(body.len());
+        let detail = Some(body[..detail_end].trim())
+            .filter(|detail| !detail.is_empty())
+            .map(String::from);
+        // Lemma
+        let (lemma, group_start) = match body[detail_end..].strip_prefix('=') {
+            Some(rest) => {
+                let end = rest.find(&[':', '!'][..]).unwrap_or(rest.len());
+                let offset = word_end + detail_end + 1;
+                let lemma = LemmaRef::parse(&rest[..end], notation)
+                    .map_err(|(e, span)| (e, offset + span.start..offset + span.end))?;
+                (Some(lemma), detail_end + 1 + end)
+            }
+            None => (None, detail_end),
+        };
+        // Group
+        let groups = &body[group_start..];
+        if let Some((second, _)) = groups.match_indices(&[':', '!'][..]).nth(1) {
+            let span = word_end + group_start + second..word_end + body.trim_end().len();
+            let err = WordParseError::MoreThanOneGroup(word.to_lowercase());
+            return Err((err, span));
+        }
+        let group = Some(groups)
+            .filter(|group| !group.is_empty())
+            .map(|group| GroupRef {
+                name: group[1..].trim().to_string(),
+                inverted: group.starts_with('!'),
+            })
+            .filter(|group| !group.name.is_empty());
+        // Explanation
+        let explanation = &left[body_end..];
+        let offset = word_end + body_end + 1;
+        let explanation = if let Some(tag) = explanation.strip_prefix('>') {
+            let tag = tag.trim();
+            if tag.is_empty() {
+                return Err((WordParseError::NoExplanationTag, offset - 1..offset));
+            }
+            Some(ExplanationRef::Tag(tag.to_string()))
+        } else {
+            explanation
+                .strip_prefix('<')
+                .map(str::trim)
+                .filter(|text| !text.is_empty())
+                .map(|text| ExplanationRef::Text(text.to_string()))
+        };
+
+        Ok(WordLine {
+            word: normalized,
+            written: word,
+            detail,
+            lemma,
+            group,
+            explanation,
+        })
+    }
+
+    /// Get word with detail and lemma as they are written, that is the first column of line.
+    ///
+    /// Phrase is quoted, so it isn't confused with detail.
+    pub fn head(&self) -> String {
+        let mut head = match self.written.contains(char::is_whitespace) {
+            true => format!("\"{}\"", self.written),
+            false => self.written.clone(),
+        };
+        if let Some(detail) = &self.detail {
+            head = format!("{head} {detail}");
+        }
+        if let Some(lemma) = &self.lemma {
+            head = format!("{head} = {lemma}");
+        }
+        head
+    }
+
+    /// Get group, that is the second column of line.
+    pub fn group(&self) -> Option<String> {
+        self.group.as_ref().map(|group| {
+            let marker = if group.inverted { '!' } else { ':' };
+            format!("{marker} {}", group.name)
+        })
+    }
+
+    /// Get explanation, that is the third column of line.
+    pub fn explanation(&self) -> Option<String> {
+        self.explanation
+            .as_ref()
+            .map(|explanation| match explanation {
+                ExplanationRef::Tag(tag) => format!("> {tag}"),
+                ExplanationRef::Text(text) => format!("< {text}"),
+            })
+    }
+}
+
+impl LemmaRef {
+    /// Parse lemma with optional form, written after `=`.
+    ///
+    /// On failure returns error with byte range of `raw` that caused it.
+    fn parse(raw: &str, notation: Notation) -> Result<LemmaRef, Spanned<WordParseError>> {
+        let start = raw.len() - raw.trim_start().len();
+        let text = raw.trim();
+        if text.is_empty() {
+            return Err((WordParseError::NoLemma, 0..raw.len()));
+        }
+        let end = text.find(char::is_whitespace).unwrap_or(text.len());
+        let written = &text[..end];
+        let word = notation
+            .normalize(written)
+            .map_err(|e| (e, start..start + end))?;
+        let form = Some(text[end..].trim())
+            .filter(|form| !form.is_empty())
+            .map(String::from);
+        Ok(LemmaRef {
+            word,
+            written: written.to_string(),
+            form,
+        })
+    }
+}
+
+impl Display for LemmaRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.form {
+            Some(form) => write!(f, "{} {form}", self.written),
+            None => write!(f, "{}", self.written),
+        }
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Line::Blank => Ok(()),
+            Line::Comment(text) => write!(f, "//{}", text.trim_end()),
+            Line::Section(name) => write!(f, "# {name}"),
+            Line::Explanation { tag, text } => write!(f, "> {tag}: {text}"),
+            Line::Word(word) => write!(f, "{word}"),
+            Line::Continuation(text) => write!(f, "| {text}"),
+        }
+    }
+}
+
+impl Display for WordLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let columns = [Some(self.head()), self.group(), self.explanation()];
+        let line = columns.into_iter().flatten().collect::<Vec<_>>().join(" ");
+        write!(f, "{line}")
+    }
+}
@@ -1,6 +1,11 @@
 use std::fmt::Display;
 
-use crate::util;
+use crate::text;
+
+use super::{
+    stress::{StressStyle, Stressed},
+    syllable::syllables,
+};
 
 /// Variant is possibly incorrect way of setting emphasis at word.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -10,14 +15,32 @@ pub struct Variant {
     pub detail: Option<String>,
 }
 
+impl Variant {
+    /// Get variant, which stressed letter is written in `style`.
+    ///
+    /// `ё` is written as `е`, so it doesn't give away the answer.
+    pub fn stressed(&self, style: StressStyle) -> Stressed {
+        let word = self.word.replace('ё', "е");
+        Stressed::new(&word, self.emphasis, style).with_detail(self.detail.as_deref())
+    }
+
+    /// Get syllable with stressed letter of this variant.
+    ///
+    /// `ё` is written as `е`, so it doesn't give away the answer.
+    pub fn syllable(&self) -> String {
+        let word = self.word.replace('ё', "е");
+        let index = text::vowel_positions(&word)
+            .iter()
+            .position(|&pos| pos == self.emphasis)
+            .unwrap_or_default();
+        syllables(&word)
+            .get(index)
+            .map_or_else(String::new, |syllable| syllable.to_string())
+    }
+}
+
 impl Display for Variant {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let word = self.word.replace('ё', "е");
-        let word = util::uppercase_letter(&word, self.emphasis);
-        if let Some(detail) = &self.detail {
-            write!(f, "{} {}", word, detail)
-        } else {
-            write!(f, "{}", word)
-        }
+        write!(f, "{}", self.stressed(StressStyle::Uppercase))
     }
 }
@@ -2,47 +2,87 @@ use std::{fmt::Display, hash::Hash, num::ParseIntError, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 
-use crate::util;
+use crate::text;
 
-use super::variant::Variant;
+use super::{
+    stress::{StressStyle, Stressed},
+    variant::Variant,
+};
 
 /// Correct way to set emphasis at `word`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Word {
     /// Preevaluated hash of word.
     hash: WordHash,
+    /// Hash that word with detail had before detail became a part of its identity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    legacy_hash: Option<WordHash>,
     /// Word in lowercase.
     inner: String,
     /// Detail that defines correct emphasis.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub detail: Option<String>,
     /// Position of correct emphasis.
     pub emphasis: usize,
-    /// Words with same seealso value are shown after failure.
-    pub group: Option<(bool, u64)>,
+    /// Positions of letters with secondary stress. They are shown, but never asked.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secondary: Vec<usize>,
+    /// Lemma that word is a form of. Forms of one lemma are shown together after failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub form: Option<WordForm>,
+    /// Words with the same group are shown after failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<WordGroup>,
     /// Explanation with presented tag shown after failute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub explanation: Option<String>,
+    /// Section of word list the word belongs to, usually part of speech.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
 }
 
 impl Word {
     pub fn new(word: &str, emphasis: usize) -> Self {
-        let word = word.to_lowercase();
+        let word = text::lowercase(word);
         Word {
-            hash: WordHash::new(&word.to_lowercase()),
-            inner: word.to_lowercase(),
+            hash: WordHash::new(&word),
+            legacy_hash: None,
+            inner: word,
             detail: None,
             emphasis,
+            secondary: Vec::new(),
+            form: None,
             group: None,
             explanation: None,
+            category: None,
         }
     }
 
+    /// Set detail of word.
+    ///
+    /// Detail is a part of word's identity, so homographs with different details aren't merged.
     pub fn with_detail(mut self, detail: &str) -> Self {
         self.detail = Some(detail.trim().to_string());
+        self.legacy_hash = Some(WordHash::new(&self.inner));
+        self.hash = WordHash::new(&self.key());
+        self
+    }
+
+    pub fn with_secondary(mut self, positions: Vec<usize>) -> Self {
+        self.secondary = positions;
+        self
+    }
+
+    pub fn with_form(mut self, form: WordForm) -> Self {
+        self.form = Some(form);
         self
     }
 
     pub fn with_group(mut self, group: &str, inverted: bool) -> Self {
-        self.group = Some((inverted, fxhash::hash64(&group.to_lowercase())));
+        self.group = Some(WordGroup {
+            name: group.trim().to_string(),
+            inverted,
+        });
         self
     }
 
@@ -51,6 +91,28 @@ impl Word {
         self
     }
 
+    pub fn with_category(mut self, category: &str) -> Self {
+        self.category = Some(category.trim().to_string());
+        self
+    }
+
+    /// Make hash of word unique to deck, so progress on it isn't shared with other decks.
+    pub fn scoped(mut self, deck: &str) -> Self {
+        self.hash = WordHash::new(&format!("{deck}:{}", self.key()));
+        if self.detail.is_some() {
+            self.legacy_hash = Some(WordHash::new(&format!("{deck}:{}", self.inner)));
+        }
+        self
+    }
+
+    /// Get string that identifies word, that is lowercase word with detail.
+    pub(super) fn key(&self) -> String {
+        match &self.detail {
+            Some(detail) => format!("{} {}", self.inner, detail.to_lowercase()),
+            None => self.inner.clone(),
+        }
+    }
+
     /// Get inner word in lowercase. Use `to_string` to get string with emphasis uppercased.
     pub fn inner(&self) -> &str {
         &self.inner
@@ -61,8 +123,30 @@ impl Word {
         self.hash
     }
 
+    /// Get hash that progress on word with detail was stored under by older versions.
+    pub fn legacy_hash(&self) -> Option<WordHash> {
+        self.legacy_hash
+    }
+
+    /// Get word with detail, which stressed letter is written in `style`.
+    pub fn stressed(&self, style: StressStyle) -> Stressed {
+        Stressed::new(&self.inner, self.emphasis, style)
+            .with_secondary(&self.secondary)
+            .with_detail(self.detail.as_deref())
+    }
+
+    /// Get lemma of word in lowercase with position of its emphasis.
+    ///
+    /// Word that isn't a form of another one is a lemma itself.
+    pub fn lemma(&self) -> (&str, usize) {
+        match &self.form {
+            Some(form) => (&form.lemma, form.emphasis),
+            None => (&self.inner, self.emphasis),
+        }
+    }
+
     pub fn variants(&self) -> Vec<Variant> {
-        util::get_vowel_positions(&self.inner)
+        text::vowel_positions(&self.inner)
             .into_iter()
             .map(|emphasis| Variant {
                 emphasis,
@@ -75,12 +159,39 @@ impl Word {
 
 impl Display for Word {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let word = util::uppercase_letter(&self.inner, self.emphasis);
-        if let Some(detail) = &self.detail {
-            write!(f, "{} {}", word, detail)
-        } else {
-            write!(f, "{}", word)
-        }
+        write!(f, "{}", self.stressed(StressStyle::Uppercase))
+    }
+}
+
+/// Grammatical form of word, that links it to its lemma.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordForm {
+    /// Lemma in lowercase.
+    pub lemma: String,
+    /// Position of emphasis of lemma.
+    pub emphasis: usize,
+    /// Name of form as it is written, like `(р.п.)`.
+    pub name: Option<String>,
+}
+
+/// Group of words with common rule of emphasis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordGroup {
+    /// Name of group as it is written in word list.
+    pub name: String,
+    /// Word is an exception to the rule of group.
+    pub inverted: bool,
+}
+
+impl WordGroup {
+    /// Get identifier of group, which is its name in lowercase.
+    pub fn id(&self) -> String {
+        self.name.to_lowercase()
+    }
+
+    /// Returns true if groups are the same, regardless of whether words are exceptions.
+    pub fn same(&self, other: &WordGroup) -> bool {
+        self.id() == other.id()
     }
 }
 
@@ -96,7 +207,7 @@ impl WordHash {
 
 impl From<&Word> for WordHash {
     fn from(val: &Word) -> Self {
-        Self::new(&val.inner)
+        val.hash
     }
 }
 
@@ -4,30 +4,49 @@
 
 use wasm_bindgen::JsCast;
 use web_sys::HtmlButtonElement;
-use yew::{html, Callback, Component, Context, Html, MouseEvent, Properties};
+use yew::{
+    context::ContextHandle, html, Callback, Component, Context, Html, MouseEvent, Properties,
+};
 
-use crate::model::{Variant, Word};
+use crate::actions::word_actions;
+use crate::model::{Settings, Variant, VariantLayout, Word, WordState};
+use crate::stress::stressed;
 
 #[derive(PartialEq, Properties)]
 pub struct QuestionProperties {
     pub callback: Callback<crate::Msg>,
     pub word: Word,
     pub variants: Vec<Variant>,
+    /// Whether previous answer may be undone.
+    pub undo: bool,
+    pub state: WordState,
 }
 
 pub enum QuestionMsg {
     Answer { emphasis: usize },
     Continue,
+    Undo,
+    Settings(Settings),
 }
 
-pub struct QuestionCard;
+pub struct QuestionCard {
+    settings: Settings,
+    _handle: ContextHandle<Settings>,
+}
 
 impl Component for QuestionCard {
     type Message = QuestionMsg;
     type Properties = QuestionProperties;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        QuestionCard
+    fn create(ctx: &Context<Self>) -> Self {
+        let (settings, handle) = ctx
+            .link()
+            .context(ctx.link().callback(QuestionMsg::Settings))
+            .expect("Settings context is not provided");
+        QuestionCard {
+            settings,
+            _handle: handle,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -41,33 +60,60 @@ impl Component for QuestionCard {
                 };
                 ctx.props().callback.emit(msg);
             }
-            QuestionMsg::Continue => ctx
-                .props()
-                .callback
-                .emit(crate::Msg::Failure(ctx.props().word.clone())),
+            QuestionMsg::Continue => {
+                let word = ctx.props().word.clone();
+                let msg = if self.settings.skip_is_failure {
+                    crate::Msg::Failure(word)
+                } else {
+                    crate::Msg::Skip(word)
+                };
+                ctx.props().callback.emit(msg);
+            }
+            QuestionMsg::Undo => ctx.props().callback.emit(crate::Msg::Undo),
+            QuestionMsg::Settings(settings) => self.settings = settings,
         }
         true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let on_continue = ctx.link().callback(|_| QuestionMsg::Continue);
+        let skip = self.settings.skip;
+        let undo = ctx.props().undo && self.settings.undo;
 
         html! {
             <>
                 <div class="panel-block">
-                    {ctx.props().variants.iter().map(|v| render_variant(ctx, v)).collect::<Html>()}
-                </div>
-                <div class="panel-block">
-                    <button class="button is-danger is-outlined is-fullwidth" onclick={on_continue} >
-                    {"Пропустить"}
-                    </button>
+                    if self.settings.variants == VariantLayout::Syllables {
+                        <div class="buttons has-addons syllables">
+                            {ctx.props().variants.iter().map(|v| render_variant(ctx, v, &self.settings)).collect::<Html>()}
+                        </div>
+                        if let Some(detail) = &ctx.props().word.detail {
+                            <span class="syllables-detail">{detail}</span>
+                        }
+                    } else {
+                        {ctx.props().variants.iter().map(|v| render_variant(ctx, v, &self.settings)).collect::<Html>()}
+                    }
                 </div>
+                if skip || undo {
+                    <div class="panel-block">
+                        if skip {
+                            <button class="button is-danger is-outlined is-fullwidth" onclick={ctx.link().callback(|_| QuestionMsg::Continue)} >
+                            {"Пропустить"}
+                            </button>
+                        }
+                        if undo {
+                            <button class="button is-warning is-outlined" onclick={ctx.link().callback(|_| QuestionMsg::Undo)}>
+                            {"Отменить ответ"}
+                            </button>
+                        }
+                    </div>
+                }
+                {word_actions(&ctx.props().callback, ctx.props().word.hash(), ctx.props().state)}
             </>
         }
     }
 }
 
-fn render_variant(ctx: &Context<QuestionCard>, variant: &Variant) -> Html {
+fn render_variant(ctx: &Context<QuestionCard>, variant: &Variant, settings: &Settings) -> Html {
     let onclick = ctx.link().batch_callback(|e: MouseEvent| {
         e.target()
             .and_then(|t| t.dyn_into::<HtmlButtonElement>().ok())
@@ -87,5 +133,9 @@ fn render_variant(ctx: &Context<QuestionCard>, variant: &Variant) -> Html {
             .map(|emphasis: usize| QuestionMsg::Answer { emphasis })
     });
     let id = format!("variant:{}", variant.emphasis);
-    html!(<button id={id} class="button is-link is-outlined" {onclick}>{&variant}</button>)
+    let label = match settings.variants {
+        VariantLayout::Words => stressed(&variant.stressed(settings.stress)),
+        VariantLayout::Syllables => html!({ variant.syllable() }),
+    };
+    html!(<button id={id} class="button is-link is-outlined" {onclick}>{label}</button>)
 }
@@ -0,0 +1,114 @@
+//! Routing between views of application.
+//!
+//! Route is stored in URL fragment, so any page may be bookmarked without server-side rewrites.
+
+use std::{fmt::Display, str::FromStr};
+
+use gloo::utils::window;
+
+use crate::model::WordHash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Route {
+    Trainer,
+    Dashboard,
+    Words,
+    Word(WordHash),
+    Decks,
+    /// Word list shared via link, encoded with [share::encode](crate::model::share::encode).
+    Shared(String),
+    Settings,
+}
+
+impl Route {
+    /// Get route from current URL. Unknown routes lead to trainer.
+    pub fn current() -> Self {
+        window()
+            .location()
+            .hash()
+            .ok()
+            .and_then(|hash| hash.parse().ok())
+            .unwrap_or(Route::Trainer)
+    }
+
+    /// Navigate to route.
+    pub fn push(&self) {
+        let _ = window().location().set_hash(&self.to_string());
+    }
+
+    /// Get link to route.
+    pub fn href(&self) -> String {
+        format!("#{self}")
+    }
+
+    /// Get absolute link to route that may be shared.
+    pub fn url(&self) -> String {
+        let location = window().location();
+        let origin = location.origin().unwrap_or_default();
+        let path = location.pathname().unwrap_or_default();
+        format!("{origin}{path}{}", self.href())
+    }
+}
+
+impl Display for Route {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Route::Trainer => write!(f, "/"),
+            Route::Dashboard => write!(f, "/dashboard"),
+            Route::Words => write!(f, "/words"),
+            Route::Word(hash) => write!(f, "/words/{hash}"),
+            Route::Decks => write!(f, "/decks"),
+            Route::Shared(payload) => write!(f, "/shared/{payload}"),
+            Route::Settings => write!(f, "/settings"),
+        }
+    }
+}
+
+impl FromStr for Route {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_start_matches('#').trim_end_matches('/');
+        let route = match s.split('/').collect::<Vec<_>>().as_slice() {
+            [] | [""] => Route::Trainer,
+            ["", "dashboard"] => Route::Dashboard,
+            ["", "words"] => Route::Words,
+            ["", "words", hash] => Route::Word(hash.parse().map_err(|_| ())?),
+            ["", "decks"] => Route::Decks,
+            ["", "shared", payload] if !payload.is_empty() => Route::Shared(payload.to_string()),
+            ["", "settings"] => Route::Settings,
+            _ => return Err(()),
+        };
+        Ok(route)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Route;
+
+    #[test]
+    fn test_route_roundtrip() {
+        let routes = [
+            Route::Trainer,
+            Route::Dashboard,
+            Route::Words,
+            Route::Word("42".parse().unwrap()),
+            Route::Decks,
+            Route::Shared(String::from("eJwrSS0uAQAEXQHB")),
+            Route::Settings,
+        ];
+        for route in routes {
+            assert_eq!(route.href().parse(), Ok(route));
+        }
+    }
+
+    #[test]
+    fn test_unknown_route() {
+        assert_eq!("".parse(), Ok(Route::Trainer));
+        assert_eq!("#/words/".parse(), Ok(Route::Words));
+        assert_eq!("#/unknown".parse::<Route>(), Err(()));
+        assert_eq!("#/words/abc".parse::<Route>(), Err(()));
+        assert_eq!("#/shared/".parse::<Route>(), Err(()));
+    }
+}
@@ -0,0 +1,163 @@
+//! Settings card allows user to configure trainer.
+
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::{
+    context::ContextHandle, html, Callback, Component, Context, Event, Html, Properties, TargetCast,
+};
+
+use crate::model::{CardOrder, Settings, StressStyle, Theme, VariantLayout};
+use crate::route::Route;
+
+#[derive(PartialEq, Properties)]
+pub struct SettingsProperties {
+    pub callback: Callback<crate::Msg>,
+}
+
+pub enum SettingsMsg {
+    Context(Settings),
+    Change(Box<dyn FnOnce(&mut Settings)>),
+}
+
+pub struct SettingsCard {
+    settings: Settings,
+    _handle: ContextHandle<Settings>,
+}
+
+impl Component for SettingsCard {
+    type Message = SettingsMsg;
+    type Properties = SettingsProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (settings, handle) = ctx
+            .link()
+            .context(ctx.link().callback(SettingsMsg::Context))
+            .expect("Settings context is not provided");
+        SettingsCard {
+            settings,
+            _handle: handle,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            SettingsMsg::Context(settings) => self.settings = settings,
+            SettingsMsg::Change(change) => {
+                let mut settings = self.settings.clone();
+                change(&mut settings);
+                ctx.props().callback.emit(crate::Msg::Settings(settings));
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let settings = &self.settings;
+
+        html! {
+            <>
+                <div class="panel-block settings">
+                    {number(ctx, "Длина сессии (0 — без ограничения)", settings.session_length, Settings::MAX_SESSION_LENGTH, |s, v| s.session_length = v)}
+                    <div class="field">
+                        <label class="label">{"Порядок слов"}</label>
+                        {select(ctx, &CardOrder::ALL, settings.order, CardOrder::name, |s, v| s.order = v)}
+                    </div>
+                    {checkbox(ctx, "Показывать верный ответ", settings.success_card, |s, v| s.success_card = v)}
+                    {number(ctx, "Переход к следующему слову, секунд (0 — вручную)", settings.success_delay, Settings::MAX_SUCCESS_DELAY, |s, v| s.success_delay = v)}
+                    {checkbox(ctx, "Разрешить отмену ответа", settings.undo, |s, v| s.undo = v)}
+                    {checkbox(ctx, "Показывать кнопку «Пропустить»", settings.skip, |s, v| s.skip = v)}
+                    {checkbox(ctx, "Считать пропуск ошибкой", settings.skip_is_failure, |s, v| s.skip_is_failure = v)}
+                    {checkbox(ctx, "Показывать таймер", settings.timer, |s, v| s.timer = v)}
+                    <div class="field">
+                        <label class="label">{"Ударная гласная"}</label>
+                        {select(ctx, &StressStyle::ALL, settings.stress, StressStyle::name, |s, v| s.stress = v)}
+                    </div>
+                    <div class="field">
+                        <label class="label">{"Варианты ответа"}</label>
+                        {select(ctx, &VariantLayout::ALL, settings.variants, VariantLayout::name, |s, v| s.variants = v)}
+                    </div>
+                    <div class="field">
+                        <label class="label">{"Тема"}</label>
+                        {select(ctx, &Theme::ALL, settings.theme, Theme::name, |s, v| s.theme = v)}
+                    </div>
+                </div>
+                <div class="panel-block">
+                    <a class="button is-primary is-fullwidth" href={Route::Trainer.href()}>
+                    {"Готово"}
+                    </a>
+                </div>
+            </>
+        }
+    }
+}
+
+fn checkbox(
+    ctx: &Context<SettingsCard>,
+    label: &'static str,
+    checked: bool,
+    set: fn(&mut Settings, bool),
+) -> Html {
+    let onchange = ctx.link().callback(move |e: Event| {
+        let value = e.target_unchecked_into::<HtmlInputElement>().checked();
+        SettingsMsg::Change(Box::new(move |s| set(s, value)))
+    });
+    html! {
+        <div class="field">
+            <label class="checkbox">
+                <input type="checkbox" {checked} {onchange}/>
+                {" "}{label}
+            </label>
+        </div>
+    }
+}
+
+fn number(
+    ctx: &Context<SettingsCard>,
+    label: &'static str,
+    value: u32,
+    max: u32,
+    set: fn(&mut Settings, u32),
+) -> Html {
+    let onchange = ctx.link().batch_callback(move |e: Event| {
+        e.target_unchecked_into::<HtmlInputElement>()
+            .value()
+            .parse::<u32>()
+            .ok()
+            .map(|value| value.min(max))
+            .map(|value| SettingsMsg::Change(Box::new(move |s| set(s, value))))
+    });
+    html! {
+        <div class="field">
+            <label class="label">{label}</label>
+            <input class="input" type="number" min="0" max={max.to_string()} value={value.to_string()} {onchange}/>
+        </div>
+    }
+}
+
+fn select<T: Copy + PartialEq + 'static>(
+    ctx: &Context<SettingsCard>,
+    options: &'static [T],
+    selected: T,
+    name: fn(&T) -> &'static str,
+    set: fn(&mut Settings, T),
+) -> Html {
+    let onchange = ctx.link().batch_callback(move |e: Event| {
+        let index = e
+            .target_unchecked_into::<HtmlSelectElement>()
+            .selected_index();
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| options.get(i).copied())
+            .map(|value| SettingsMsg::Change(Box::new(move |s| set(s, value))))
+    });
+    html! {
+        <div class="select">
+            <select {onchange}>
+                {
+                    options.iter().map(|option| html! {
+                        <option selected={*option == selected}>{name(option)}</option>
+                    }).collect::<Html>()
+                }
+            </select>
+        </div>
+    }
+}
@@ -0,0 +1,126 @@
+//! Card that offers to train on word list shared via link.
+
+use yew::{html, Callback, Component, Context, Html, Properties};
+
+use crate::errors::{ParseErrors, ParseErrorsProperties};
+use crate::model::{parse, share, CustomDeck, Format, Notation, ParseError, ParseWarning};
+use crate::route::Route;
+
+#[derive(PartialEq, Properties)]
+pub struct SharedProperties {
+    pub callback: Callback<crate::Msg>,
+    /// Encoded source of word list.
+    pub payload: String,
+    /// User has custom deck that will be replaced.
+    pub replaces: bool,
+}
+
+pub struct SharedCard {
+    /// Result of decoding of the current payload, so it isn't decompressed on each render.
+    decoded: Decoded,
+}
+
+/// Word list decoded from payload along with what its parsing found.
+struct Decoded {
+    payload: String,
+    result: Result<Parsed, share::ShareError>,
+}
+
+struct Parsed {
+    source: String,
+    words: Vec<String>,
+    errors: Vec<ParseError>,
+    warnings: Vec<ParseWarning>,
+}
+
+impl Decoded {
+    fn new(payload: &str) -> Self {
+        let result = share::decode(payload).map(|source| {
+            let (words, errors, warnings) = parse(&source);
+            Parsed {
+                words: words.iter().map(ToString::to_string).collect(),
+                source,
+                errors,
+                warnings,
+            }
+        });
+        Decoded {
+            payload: payload.to_string(),
+            result,
+        }
+    }
+}
+
+impl Component for SharedCard {
+    type Message = ();
+    type Properties = SharedProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        SharedCard {
+            decoded: Decoded::new(&ctx.props().payload),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        if self.decoded.payload != ctx.props().payload {
+            self.decoded = Decoded::new(&ctx.props().payload);
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let parsed = match &self.decoded.result {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                return html! {
+                    <div class="panel-block custom-deck">
+                        <p class="has-text-danger">{"Не удалось открыть список: ссылка повреждена."}</p>
+                        <p class="deck-meta">{e.to_string()}</p>
+                    </div>
+                }
+            }
+        };
+        let summary = format!(
+            "Вам отправили список слов: слов — {}, ошибок — {}.",
+            parsed.words.len(),
+            parsed.errors.len()
+        );
+        let errors = ParseErrorsProperties {
+            file: String::from("shared"),
+            errors: parsed.errors.clone(),
+            warnings: parsed.warnings.clone(),
+        };
+        let onclick = {
+            let source = parsed.source.clone();
+            ctx.props().callback.reform(move |_| {
+                Route::Trainer.push();
+                crate::Msg::CustomDeck(Some(CustomDeck {
+                    name: String::from("Список по ссылке"),
+                    source: source.clone(),
+                    format: Format::Text,
+                    notation: Notation::Uppercase,
+                }))
+            })
+        };
+
+        html! {
+            <>
+                <div class="panel-block custom-deck">
+                    <p>{summary}</p>
+                    <p class="shared-words">{parsed.words.join(", ")}</p>
+                    <ParseErrors ..errors/>
+                </div>
+                <div class="panel-block">
+                    <button class="button is-primary is-fullwidth" {onclick} disabled={parsed.words.is_empty()}>
+                    {"Тренировать этот список"}
+                    </button>
+                </div>
+                if ctx.props().replaces {
+                    <div class="panel-block">
+                        <p class="deck-meta">{"Загруженный ранее свой список будет заменён."}</p>
+                    </div>
+                }
+            </>
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Rendering of words with stressed letter in style chosen by user.
+
+use yew::{html, Html};
+
+use crate::model::{StressStyle, Stressed};
+
+/// Render word with stressed letter.
+///
+/// Only highlighted letter needs markup, other styles are plain text.
+pub fn stressed(stressed: &Stressed) -> Html {
+    match stressed.style {
+        StressStyle::Highlight => {
+            let (before, letter, after) = stressed.parts();
+            html! {
+                <span class="stressed-word">
+                    {before}<span class="stressed">{letter}</span>{after}
+                    if let Some(detail) = &stressed.detail {
+                        {format!(" {detail}")}
+                    }
+                </span>
+            }
+        }
+        StressStyle::Uppercase | StressStyle::Accent => html!({ stressed.to_string() }),
+    }
+}
@@ -0,0 +1,100 @@
+//! Success card confirms correct answer and reminds user of related words.
+//!
+//! It moves to the next word on its own after delay set in settings.
+
+use gloo::timers::callback::Timeout;
+use yew::{context::ContextHandle, html, Callback, Component, Context, Html, Properties};
+
+use crate::failure::{explanation, paradigm, seealso};
+use crate::model::{Settings, Word, WordHash};
+use crate::stress::stressed;
+
+#[derive(PartialEq, Properties)]
+pub struct SuccessProperties {
+    pub callback: Callback<crate::Msg>,
+    pub word: Word,
+    pub seealso: Vec<Word>,
+    pub opposite: Vec<Word>,
+    /// Forms of lemma of word, including the word itself.
+    pub paradigm: Vec<Word>,
+    /// Words that explanation refers to, along with text of reference.
+    pub links: Vec<(String, WordHash)>,
+    /// Whether answer may be undone.
+    pub undo: bool,
+}
+
+pub enum SuccessMsg {
+    Continue,
+    Undo,
+    Settings(Settings),
+}
+
+pub struct SuccessCard {
+    settings: Settings,
+    _handle: ContextHandle<Settings>,
+    _timeout: Option<Timeout>,
+}
+
+impl Component for SuccessCard {
+    type Message = SuccessMsg;
+    type Properties = SuccessProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (settings, handle) = ctx
+            .link()
+            .context(ctx.link().callback(SuccessMsg::Settings))
+            .expect("Settings context is not provided");
+        // Settings may come from older version that didn't limit delay.
+        let delay = settings.success_delay.min(Settings::MAX_SUCCESS_DELAY);
+        let timeout = (delay > 0).then(|| {
+            let callback = ctx.link().callback(|_: ()| SuccessMsg::Continue);
+            Timeout::new(delay * 1000, move || callback.emit(()))
+        });
+        SuccessCard {
+            settings,
+            _handle: handle,
+            _timeout: timeout,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            SuccessMsg::Continue => ctx.props().callback.emit(crate::Msg::NextWord),
+            SuccessMsg::Undo => ctx.props().callback.emit(crate::Msg::Undo),
+            SuccessMsg::Settings(settings) => self.settings = settings,
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let on_continue = ctx.link().callback(|_| SuccessMsg::Continue);
+        let style = self.settings.stress;
+
+        html! {
+            <>
+                <div class="panel-block answer-status" role="status">{"✓ Верно"}</div>
+                <div class="panel-block success">
+                    <b class="failure-word">{stressed(&ctx.props().word.stressed(style))}</b>
+                </div>
+                {paradigm(&ctx.props().word, &ctx.props().paradigm, style)}
+                {seealso(&ctx.props().word, &ctx.props().seealso, &ctx.props().opposite, style)}
+                {explanation(&ctx.props().word, &ctx.props().links, style)}
+                <div class="panel-block">
+                    <button class="button is-success is-fullwidth" onclick={on_continue} >
+                    {"Продолжить"}
+                    </button>
+                    {undo(self, ctx)}
+                </div>
+            </>
+        }
+    }
+}
+
+fn undo(card: &SuccessCard, ctx: &Context<SuccessCard>) -> Html {
+    if ctx.props().undo && card.settings.undo {
+        let onclick = ctx.link().callback(|_| SuccessMsg::Undo);
+        html!(<button class="button is-warning is-outlined" {onclick}>{"Отменить"}</button>)
+    } else {
+        html!()
+    }
+}
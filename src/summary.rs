@@ -0,0 +1,34 @@
+//! Summary card is shown when session is over.
+
+use std::time::Duration;
+
+use yew::{function_component, html, Callback, Properties};
+
+use crate::header::format_time;
+
+#[derive(PartialEq, Properties)]
+pub struct SummaryProperties {
+    pub callback: Callback<crate::Msg>,
+    pub time: Duration,
+    pub words: u32,
+    pub answered: u32,
+}
+
+#[function_component(SummaryCard)]
+pub fn summary_card(props: &SummaryProperties) -> Html {
+    let onclick = props.callback.reform(|_| crate::Msg::Restart);
+
+    html! {
+        <>
+            <div class="panel-block summary">
+                <p>{format!("Сессия завершена за {}.", format_time(props.time))}</p>
+                <p>{format!("Верных ответов: {} из {}.", props.words, props.answered)}</p>
+            </div>
+            <div class="panel-block">
+                <button class="button is-primary is-fullwidth" {onclick}>
+                {"Начать новую сессию"}
+                </button>
+            </div>
+        </>
+    }
+}
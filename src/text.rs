@@ -0,0 +1,147 @@
+//! Letters of words.
+//!
+//! Position of letter is an index of grapheme cluster, so combining marks belong to their letter
+//! and changes of length on case conversion don't shift following letters.
+//! Hyphens and spaces are counted as letters too, so positions are the same in any function here.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+const VOWELS: [char; 10] = ['а', 'у', 'о', 'и', 'э', 'ы', 'я', 'ю', 'е', 'ё'];
+
+/// Get letters of `s`.
+pub fn letters(s: &str) -> Vec<&str> {
+    s.graphemes(true).collect()
+}
+
+/// Get number of letters of `s`.
+pub fn len(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// Returns true if letter is a vowel in any case, with or without combining marks.
+pub fn is_vowel(letter: &str) -> bool {
+    letter
+        .chars()
+        .next()
+        .is_some_and(|c| c.to_lowercase().any(|c| VOWELS.contains(&c)))
+}
+
+/// Returns true if letter is uppercase.
+pub fn is_uppercase(letter: &str) -> bool {
+    letter.chars().next().is_some_and(char::is_uppercase)
+}
+
+/// Get position of all vowels of `s`.
+pub fn vowel_positions(s: &str) -> Vec<usize> {
+    s.graphemes(true)
+        .enumerate()
+        .filter(|(_, letter)| is_vowel(letter))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// Get position of the first uppercase letter of `s`.
+pub fn uppercase_position(s: &str) -> Option<usize> {
+    s.graphemes(true).position(is_uppercase)
+}
+
+/// Make letter of `s` at `pos` uppercase, keeping the other ones as they are.
+pub fn uppercase_letter(s: &str, pos: usize) -> String {
+    s.graphemes(true)
+        .enumerate()
+        .map(|(p, letter)| match p == pos {
+            true => letter.to_uppercase(),
+            false => letter.to_string(),
+        })
+        .collect()
+}
+
+/// Make all letters of `s` lowercase.
+///
+/// Unlike `str::to_lowercase`, keeps the number of letters.
+pub fn lowercase(s: &str) -> String {
+    s.graphemes(true).map(lowercase_letter).collect()
+}
+
+/// Make letter lowercase, keeping it a single letter.
+fn lowercase_letter(letter: &str) -> String {
+    let lower = letter.to_lowercase();
+    match lower.graphemes(true).count() {
+        1 => lower,
+        _ => letter.to_string(),
+    }
+}
+
+/// Split `s` into text before letter at `pos`, the letter itself and text after it.
+///
+/// Letter is empty if `s` is shorter.
+pub fn split_at_letter(s: &str, pos: usize) -> (&str, &str, &str) {
+    let mut indices = s.grapheme_indices(true).skip(pos);
+    match indices.next() {
+        Some((start, letter)) => {
+            let end = start + letter.len();
+            (&s[..start], letter, &s[end..])
+        }
+        None => (s, "", ""),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::{
+        len, letters, lowercase, split_at_letter, uppercase_letter, uppercase_position,
+        vowel_positions,
+    };
+
+    #[test]
+    fn test_combining_marks() {
+        let word = "моло\u{301}ко";
+        assert_eq!(len(word), 6);
+        assert_eq!(vowel_positions(word), vec![1, 3, 5]);
+        assert_eq!(split_at_letter(word, 3), ("мол", "о\u{301}", "ко"));
+        assert_eq!(uppercase_letter(word, 3), "молО\u{301}ко");
+    }
+
+    #[test]
+    fn test_phrase() {
+        let phrase = "по-морю на дом";
+        assert_eq!(vowel_positions(phrase), vec![1, 4, 6, 9, 12]);
+        assert_eq!(uppercase_position(&uppercase_letter(phrase, 12)), Some(12));
+    }
+
+    #[test]
+    fn test_case_changes_length() {
+        // Lowercase `İ` is two chars, but still one letter.
+        let word = "İва";
+        assert_eq!(len(&lowercase(word)), len(word));
+        assert_eq!(vowel_positions(&lowercase(word)), vec![2]);
+    }
+
+    /// Russian words with occasional hyphens, spaces and combining marks.
+    fn word() -> impl Strategy<Value = String> {
+        "[а-яё]([а-яё\\- ]|\u{301}){0,20}"
+            .prop_map(|s| s.trim().replace("\u{301}\u{301}", "\u{301}"))
+    }
+
+    proptest! {
+        #[test]
+        fn test_uppercase_roundtrip(word in word(), pos in 0usize..20) {
+            let pos = pos % len(&word).max(1);
+            let upper = uppercase_letter(&word, pos);
+            prop_assert_eq!(len(&upper), len(&word));
+            if letters(&word)[pos].chars().next().is_some_and(char::is_alphabetic) {
+                prop_assert_eq!(uppercase_position(&upper), Some(pos));
+            }
+            prop_assert_eq!(lowercase(&upper), word);
+        }
+
+        #[test]
+        fn test_split_roundtrip(word in word(), pos in 0usize..20) {
+            let (before, letter, after) = split_at_letter(&word, pos);
+            prop_assert_eq!(format!("{before}{letter}{after}"), word.clone());
+            prop_assert_eq!(len(before), pos.min(len(&word)));
+        }
+    }
+}
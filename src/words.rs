@@ -0,0 +1,243 @@
+//! Searchable list of all words and card with details of a single word.
+
+use web_sys::HtmlInputElement;
+use yew::{
+    classes, function_component, html, use_context, use_state, Callback, Html, InputEvent,
+    Properties, TargetCast,
+};
+
+use crate::actions::word_actions;
+use crate::failure::{explanation, paradigm, seealso};
+use crate::model::{
+    markup, share, Document, Group, Settings, StressStyle, Word, WordHash, WordState,
+};
+use crate::route::Route;
+use crate::stress::stressed;
+
+/// Word with user's progress on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordEntry {
+    pub word: Word,
+    pub group: Group,
+    pub state: WordState,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct WordListProperties {
+    pub callback: Callback<crate::Msg>,
+    pub entries: Vec<WordEntry>,
+    /// Report on flagged words.
+    pub report: String,
+}
+
+#[function_component(WordList)]
+pub fn word_list(props: &WordListProperties) -> Html {
+    let style = use_context::<Settings>()
+        .expect("Settings context is not provided")
+        .stress;
+    // Link to share found words, reset when search changes.
+    let link = use_state(|| None);
+    let query = use_state(String::new);
+    let oninput = {
+        let query = query.clone();
+        let link = link.clone();
+        Callback::from(move |e: InputEvent| {
+            query.set(e.target_unchecked_into::<HtmlInputElement>().value());
+            link.set(None);
+        })
+    };
+    let flagged_only = use_state(|| false);
+    let onchange = {
+        let flagged_only = flagged_only.clone();
+        let link = link.clone();
+        Callback::from(move |_| {
+            flagged_only.set(!*flagged_only);
+            link.set(None);
+        })
+    };
+    let normalized = normalize(&query);
+    let found = props
+        .entries
+        .iter()
+        .filter(|entry| !*flagged_only || entry.state.flagged)
+        .filter(|entry| matches(&entry.word, &normalized))
+        .collect::<Vec<_>>();
+    let has_flagged = props.entries.iter().any(|entry| entry.state.flagged);
+    let share = {
+        let link = link.clone();
+        let words = found
+            .iter()
+            .map(|entry| entry.word.clone())
+            .collect::<Vec<_>>();
+        Callback::from(move |_| {
+            let payload = share::encode(&Document::from_words(&words, style).to_string());
+            link.set(Some(Route::Shared(payload).url()));
+        })
+    };
+    let report = format!(
+        "data:text/plain;charset=utf-8,{}",
+        js_sys::encode_uri_component(&props.report)
+    );
+
+    html! {
+        <>
+            <div class="panel-block">
+                <p class="control">
+                    <input class="input" type="search" placeholder="Поиск" value={(*query).clone()} {oninput}/>
+                </p>
+            </div>
+            <div class="panel-block word-count">
+                <p>{format!("Найдено слов: {}", found.len())}</p>
+                <label class="checkbox">
+                    <input type="checkbox" checked={*flagged_only} {onchange}/>
+                    {" Только с отметкой об ошибке"}
+                </label>
+                if has_flagged {
+                    <a class="button is-small" href={report} download="report.txt">
+                    {"Скачать отчёт об ошибках"}
+                    </a>
+                }
+                <button class="button is-small" onclick={share} disabled={found.is_empty()}>
+                {"Поделиться выборкой"}
+                </button>
+            </div>
+            if let Some(link) = &*link {
+                <div class="panel-block share-link">
+                    <input class="input is-small" type="text" readonly=true value={link.clone()}/>
+                    <a class="button is-small is-link is-outlined" href={link.clone()} target="_blank">
+                    {"Открыть"}
+                    </a>
+                </div>
+            }
+            {found.into_iter().map(|entry| render_entry(&props.callback, entry, style)).collect::<Html>()}
+        </>
+    }
+}
+
+fn render_entry(callback: &Callback<crate::Msg>, entry: &WordEntry, style: StressStyle) -> Html {
+    let hash = entry.word.hash();
+    let state = entry.state;
+    let suspended = state.suspended;
+    let inactive = state.suspended || state.buried;
+    let practice = callback.reform(move |_| crate::Msg::Practice(hash));
+    let suspend = callback.reform(move |_| crate::Msg::Suspend(hash, !suspended));
+
+    html! {
+        <div class={classes!("panel-block", "word-entry", inactive.then_some("is-suspended"))}>
+            <div class="word-entry-info">
+                <a href={Route::Word(hash).href()}><b>{stressed(&entry.word.stressed(style))}</b></a>
+                <div class="tags">
+                    if let Some(category) = &entry.word.category {
+                        <span class="tag">{category}</span>
+                    }
+                    if let Some(group) = &entry.word.group {
+                        <span class="tag is-primary is-light" title="Группа">{&group.name}</span>
+                    }
+                    <span class="tag is-info is-light">{format!("Уровень {}", entry.group.level())}</span>
+                    if state.suspended {
+                        <span class="tag is-warning is-light">{"Скрыто"}</span>
+                    }
+                    if state.buried {
+                        <span class="tag is-warning is-light">{"Отложено"}</span>
+                    }
+                    if state.flagged {
+                        <span class="tag is-danger is-light">{"Ошибка?"}</span>
+                    }
+                </div>
+                if let Some(explanation) = &entry.word.explanation {
+                    <p class="word-entry-explanation">{markup::plain(explanation)}</p>
+                }
+            </div>
+            <div class="buttons">
+                <button class="button is-small is-link is-outlined" onclick={practice} disabled={inactive}>
+                {"Тренировать"}
+                </button>
+                <button class="button is-small" onclick={suspend}>
+                {if suspended { "Вернуть" } else { "Скрыть" }}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+/// Prepare string for search.
+fn normalize(s: &str) -> String {
+    s.trim().to_lowercase().replace('ё', "е")
+}
+
+/// Returns true if word matches normalized search query.
+fn matches(word: &Word, query: &str) -> bool {
+    if query.is_empty() {
+        return true;
+    }
+    [
+        Some(word.inner()),
+        word.detail.as_deref(),
+        word.explanation.as_deref(),
+        word.category.as_deref(),
+        word.group.as_ref().map(|group| group.name.as_str()),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|field| normalize(field).contains(query))
+}
+
+#[derive(PartialEq, Properties)]
+pub struct WordProperties {
+    pub callback: Callback<crate::Msg>,
+    pub word: Word,
+    pub seealso: Vec<Word>,
+    pub opposite: Vec<Word>,
+    /// Forms of lemma of word, including the word itself.
+    pub paradigm: Vec<Word>,
+    /// Words that explanation refers to, along with text of reference.
+    pub links: Vec<(String, WordHash)>,
+    pub group: Group,
+    pub state: WordState,
+}
+
+#[function_component(WordCard)]
+pub fn word_card(props: &WordProperties) -> Html {
+    let style = use_context::<Settings>()
+        .expect("Settings context is not provided")
+        .stress;
+    let hash = props.word.hash();
+    let onclick = props.callback.reform(move |_| crate::Msg::Practice(hash));
+
+    html! {
+        <>
+            <div class="panel-block failure">
+                <b class="failure-word">{stressed(&props.word.stressed(style))}</b>
+            </div>
+            <div class="panel-block word-level">
+                <p>{format!("Уровень: {} из {}", props.group.level(), Group::COUNT - 1)}</p>
+            </div>
+            {paradigm(&props.word, &props.paradigm, style)}
+            {seealso(&props.word, &props.seealso, &props.opposite, style)}
+            {explanation(&props.word, &props.links, style)}
+            <div class="panel-block">
+                <button class="button is-primary is-fullwidth" {onclick}>
+                {"Тренировать"}
+                </button>
+            </div>
+            {word_actions(&props.callback, hash, props.state)}
+        </>
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{matches, normalize};
+    use crate::model::Word;
+
+    #[test]
+    fn test_search() {
+        let word = Word::new("свёкла", 2)
+            .with_category("Существительные")
+            .with_group("КОРНЕПЛОДЫ", false);
+        assert!(matches(&word, &normalize("Свекла")));
+        assert!(matches(&word, &normalize("корнеплод")));
+        assert!(matches(&word, &normalize("существ")));
+        assert!(!matches(&word, &normalize("брать")));
+    }
+}